@@ -0,0 +1,79 @@
+//! Persistent updates: a full `T::clone()` of a large state versus
+//! `DerefPath::updated`, which clones only the spine and shares the rest behind `Arc`.
+//!
+//! Run with `cargo bench -p pathmod --bench updated`.
+
+use std::sync::Arc;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pathmod::prelude::*;
+
+#[derive(Accessor, Clone)]
+struct Settings {
+    theme: String,
+    font_size: u32,
+}
+
+/// The state with every part inline: any snapshot is a deep clone.
+#[derive(Accessor, Clone)]
+struct InlineState {
+    settings: Settings,
+    history: Vec<String>,
+    index: Vec<u64>,
+}
+
+/// The same state with its parts behind `Arc`, so snapshots can share them.
+#[derive(Accessor, Clone)]
+struct SharedState {
+    settings: Arc<Settings>,
+    history: Arc<Vec<String>>,
+    index: Arc<Vec<u64>>,
+}
+
+fn settings() -> Settings {
+    Settings {
+        theme: "light".into(),
+        font_size: 12,
+    }
+}
+
+fn history() -> Vec<String> {
+    (0..1_000).map(|i| format!("event {i}")).collect()
+}
+
+fn bench_updated(c: &mut Criterion) {
+    let inline = InlineState {
+        settings: settings(),
+        history: history(),
+        index: (0..10_000).collect(),
+    };
+    let shared = SharedState {
+        settings: Arc::new(settings()),
+        history: Arc::new(history()),
+        index: Arc::new((0..10_000).collect()),
+    };
+
+    let inline_theme = InlineState::acc_settings().compose(Settings::acc_theme());
+    let shared_theme = SharedState::acc_settings()
+        .through_deref()
+        .compose(Settings::acc_theme());
+
+    let mut group = c.benchmark_group("updated theme");
+    group.bench_function("full clone + set", |b| {
+        b.iter(|| {
+            let mut next = black_box(&inline).clone();
+            inline_theme.set(&mut next, "dark".into());
+            next
+        })
+    });
+    group.bench_function("Accessor::updated (inline)", |b| {
+        b.iter(|| inline_theme.updated(black_box(&inline), "dark".into()))
+    });
+    group.bench_function("DerefPath::updated (Arc spine)", |b| {
+        b.iter(|| shared_theme.updated(black_box(&shared), "dark".into()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_updated);
+criterion_main!(benches);
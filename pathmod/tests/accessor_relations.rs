@@ -0,0 +1,132 @@
+use std::collections::{BTreeSet, HashMap};
+
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Profile {
+    address: Address,
+    bio: String,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct User {
+    id: u64,
+    profile: Profile,
+    marker: (),
+}
+
+#[test]
+fn containment_follows_the_path() {
+    let profile = User::acc_profile();
+    let address = profile.compose(Profile::acc_address());
+    let city = address.compose(Address::acc_city());
+    let bio = profile.compose(Profile::acc_bio());
+
+    assert!(profile.contains(&city));
+    assert!(address.contains(&city));
+    assert!(city.contains(&city));
+    assert!(!city.contains(&address));
+    assert!(!address.contains(&bio));
+    assert!(!User::acc_id().contains(&city));
+}
+
+#[test]
+fn overlap_and_disjointness() {
+    let profile = User::acc_profile();
+    let city = profile
+        .compose(Profile::acc_address())
+        .compose(Address::acc_city());
+    let zip = profile
+        .compose(Profile::acc_address())
+        .compose(Address::acc_zip());
+
+    assert!(profile.overlaps(&city));
+    assert!(city.overlaps(&profile));
+    assert!(city.is_disjoint(&zip));
+    assert!(User::acc_id().is_disjoint(&profile));
+    // Zero-sized fields never overlap, not even with themselves.
+    assert!(User::acc_marker().is_disjoint(&User::acc_marker()));
+}
+
+#[test]
+fn strip_prefix_inverts_compose() {
+    let profile = User::acc_profile();
+    let rest = Profile::acc_address().compose(Address::acc_zip());
+    let zip = profile.compose(rest);
+
+    assert_eq!(zip.strip_prefix(&profile), Some(rest));
+    assert_eq!(zip.strip_prefix(&User::acc_id()), None);
+    // Equal sizes cannot tell which field encloses the other.
+    assert_eq!(profile.strip_prefix(&profile), None);
+
+    // A zero-sized field sits at some boundary without belonging to its neighbours.
+    assert_eq!(User::acc_marker().strip_prefix(&profile), None);
+    assert_eq!(User::acc_marker().strip_prefix(&User::acc_marker()), None);
+}
+
+#[test]
+fn accessors_are_map_keys_and_sort_by_position() {
+    let mut labels = HashMap::new();
+    labels.insert(User::acc_id(), "id");
+    labels.insert(pathmod::path!(User => id), "same id");
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[&User::acc_id()], "same id");
+
+    let address = User::acc_profile().compose(Profile::acc_address());
+    let city = address.compose(Address::acc_city());
+    let bio = User::acc_profile().compose(Profile::acc_bio());
+    let fields: BTreeSet<_> = [bio, city, bio].into_iter().collect();
+    assert_eq!(fields.len(), 2);
+    let ordered: Vec<_> = fields.into_iter().collect();
+    assert!(ordered[0].offset() < ordered[1].offset());
+    assert_eq!(city < bio, city.offset() < bio.offset());
+}
+
+mod proof {
+    use pathmod::prelude::*;
+
+    /// Only this module can make one.
+    pub struct Proof(());
+
+    #[derive(Accessor)]
+    pub struct W {
+        pub v: u32,
+        #[pathmod(vis = "pub")]
+        proof: Proof,
+    }
+
+    #[derive(Accessor)]
+    pub struct T {
+        pub w: W,
+    }
+
+    impl T {
+        pub fn new(v: u32) -> Self {
+            T {
+                w: W {
+                    v,
+                    proof: Proof(()),
+                },
+            }
+        }
+    }
+}
+
+#[test]
+fn strip_prefix_never_views_a_field_as_its_same_size_parent() {
+    use proof::{T, W};
+
+    let w = T::acc_w();
+    let v = w.compose(W::acc_v());
+    assert!(w.contains(&v) && v.contains(&w));
+    // `Accessor<u32, W>` would let any `u32` be read as a `W`.
+    assert_eq!(w.strip_prefix(&v), None);
+    assert_eq!(v.strip_prefix(&w), None);
+    assert_eq!(*v.get(&T::new(3)), 3);
+}
@@ -0,0 +1,87 @@
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use pathmod::prelude::*;
+
+#[derive(Accessor, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Accessor, Default)]
+struct Metrics {
+    cache: Counters,
+    db: Counters,
+    healthy: AtomicBool,
+    depth: AtomicI32,
+}
+
+#[test]
+fn nested_counters_are_path_addressable() {
+    let m = Arc::new(Metrics::default());
+    let cache_hits = Metrics::acc_cache().compose(Counters::acc_hits());
+    let db_misses = Metrics::acc_db().compose(Counters::acc_misses());
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let m = Arc::clone(&m);
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    cache_hits.fetch_add(&m, 1, Ordering::Relaxed);
+                }
+                db_misses.fetch_add(&m, 1, Ordering::Relaxed);
+            })
+        })
+        .collect();
+    for w in workers {
+        w.join().unwrap();
+    }
+
+    assert_eq!(cache_hits.load(&m, Ordering::Relaxed), 400);
+    assert_eq!(db_misses.load(&m, Ordering::Relaxed), 4);
+    assert_eq!(m.cache.misses.load(Ordering::Relaxed), 0);
+    assert_eq!(m.db.hits.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn load_store_swap_and_compare_exchange() {
+    let m = Metrics::default();
+    let healthy = Metrics::acc_healthy();
+    healthy.store(&m, true, Ordering::Release);
+    assert!(healthy.load(&m, Ordering::Acquire));
+    assert!(healthy.swap(&m, false, Ordering::AcqRel));
+    assert_eq!(
+        healthy.compare_exchange(&m, true, true, Ordering::AcqRel, Ordering::Acquire),
+        Err(false)
+    );
+
+    let depth = Metrics::acc_depth();
+    assert_eq!(depth.fetch_sub(&m, 3, Ordering::Relaxed), 0);
+    assert_eq!(depth.fetch_max(&m, -5, Ordering::Relaxed), -3);
+    assert_eq!(depth.fetch_min(&m, -5, Ordering::Relaxed), -3);
+    assert_eq!(depth.load(&m, Ordering::Relaxed), -5);
+    depth.store(&m, 0b1100, Ordering::Relaxed);
+    depth.fetch_and(&m, 0b0110, Ordering::Relaxed);
+    depth.fetch_or(&m, 0b0001, Ordering::Relaxed);
+    depth.fetch_xor(&m, 0b1111, Ordering::Relaxed);
+    assert_eq!(depth.load(&m, Ordering::Relaxed), 0b1010);
+}
+
+#[test]
+fn tuple_structs_and_pointers() {
+    #[derive(Accessor)]
+    struct Slot(AtomicUsize, AtomicPtr<u8>);
+
+    let mut byte = 7u8;
+    let s = Slot(AtomicUsize::new(1), AtomicPtr::new(core::ptr::null_mut()));
+    assert_eq!(Slot::acc_0().fetch_add(&s, 1, Ordering::SeqCst), 1);
+    assert!(Slot::acc_1()
+        .swap(&s, &mut byte, Ordering::SeqCst)
+        .is_null());
+    assert_eq!(
+        Slot::acc_1().load(&s, Ordering::SeqCst),
+        &mut byte as *mut u8
+    );
+}
@@ -0,0 +1,69 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Profile {
+    address: Address,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct User {
+    id: u64,
+    profile: Profile,
+}
+
+const CITY: Accessor<User, String> = User::acc_profile()
+    .compose(Profile::acc_address())
+    .compose(Address::acc_city());
+
+static ZIP: Accessor<User, u32> = User::acc_profile()
+    .compose(Profile::acc_address())
+    .compose(Address::acc_zip());
+
+const CITY_OFFSET: isize = CITY.offset();
+
+fn user() -> User {
+    User {
+        id: 1,
+        profile: Profile {
+            address: Address {
+                city: "Paris".into(),
+                zip: 75001,
+            },
+        },
+    }
+}
+
+#[test]
+fn const_composed_accessor_reads_and_writes() {
+    let mut u = user();
+    assert_eq!(CITY.get(&u), "Paris");
+    CITY.set(&mut u, "Lyon".into());
+    assert_eq!(u.profile.address.city, "Lyon");
+}
+
+#[test]
+fn static_composed_accessor_is_usable() {
+    let mut u = user();
+    ZIP.set_mut(&mut u, |z| *z += 1);
+    assert_eq!(u.profile.address.zip, 75002);
+}
+
+#[test]
+fn offsets_are_available_at_compile_time() {
+    const TABLE: [isize; 2] = [CITY.offset(), User::acc_id().offset()];
+    assert_eq!(TABLE[0], CITY_OFFSET);
+    assert_eq!(
+        CITY_OFFSET,
+        pathmod::path!(User => profile.address.city).offset()
+    );
+
+    // Offsets can size arrays, since they are evaluated during compilation.
+    let buf = [0u8; CITY_OFFSET as usize + 1];
+    assert_eq!(buf.len() as isize, CITY_OFFSET + 1);
+}
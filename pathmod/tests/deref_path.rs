@@ -0,0 +1,246 @@
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, Clone, PartialEq)]
+struct Leaf {
+    value: i32,
+    label: String,
+}
+
+#[derive(Accessor, Debug, Clone, PartialEq)]
+struct Tree {
+    left: Option<Box<Tree>>,
+    leaf: Leaf,
+}
+
+#[derive(Accessor, Debug, Clone, PartialEq)]
+struct Config {
+    name: String,
+    limits: Limits,
+}
+
+#[derive(Accessor, Debug, Clone, PartialEq)]
+struct Limits {
+    max: u32,
+}
+
+#[derive(Accessor, Debug)]
+struct App {
+    id: u8,
+    config: Arc<Config>,
+    local: Rc<Limits>,
+}
+
+/// A user-defined smart pointer plugged in through `DerefHop`.
+#[derive(Debug)]
+struct Handle<T>(Box<T>);
+
+impl<T> Deref for Handle<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Handle<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> DerefHop for Handle<T> {}
+
+impl<T> DerefHopMut for Handle<T> {
+    fn deref_hop_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+#[derive(Accessor, Debug)]
+struct Holder {
+    inner: Handle<Box<Leaf>>,
+}
+
+#[test]
+fn arc_and_rc_hops_are_copy_on_write() {
+    let shared = Arc::new(Config {
+        name: "base".into(),
+        limits: Limits { max: 10 },
+    });
+    let local = Rc::new(Limits { max: 1 });
+    let mut app = App {
+        id: 7,
+        config: Arc::clone(&shared),
+        local: Rc::clone(&local),
+    };
+
+    let max = App::acc_config()
+        .through_deref()
+        .compose(Config::acc_limits())
+        .compose(Limits::acc_max());
+    assert_eq!(max.hop_count(), 1);
+    assert_eq!(*max.get(&app), 10);
+
+    max.set_mut(&mut app, |m| *m += 5);
+    assert_eq!(app.config.limits.max, 15);
+    assert_eq!(shared.limits.max, 10);
+    assert!(!Arc::ptr_eq(&app.config, &shared));
+
+    // Once unique, further writes do not clone again
+    let before = Arc::as_ptr(&app.config);
+    max.set(&mut app, 20);
+    assert_eq!(Arc::as_ptr(&app.config), before);
+
+    let local_max = App::acc_local().through_deref().compose(Limits::acc_max());
+    local_max.set_clone(&mut app, &3);
+    assert_eq!(app.local.max, 3);
+    assert_eq!(local.max, 1);
+    assert_eq!(app.id, 7);
+}
+
+#[test]
+fn boxed_recursive_structures_through_optional() {
+    let mut t = Tree {
+        left: Some(Box::new(Tree {
+            left: Some(Box::new(Tree {
+                left: None,
+                leaf: Leaf {
+                    value: 3,
+                    label: "ll".into(),
+                },
+            })),
+            leaf: Leaf {
+                value: 2,
+                label: "l".into(),
+            },
+        })),
+        leaf: Leaf {
+            value: 1,
+            label: "root".into(),
+        },
+    };
+
+    let left = Tree::acc_left().some().through_deref();
+    let left_left_value = left
+        .clone()
+        .compose(Tree::acc_left())
+        .some()
+        .through_deref()
+        .compose(Tree::acc_leaf())
+        .compose(Leaf::acc_value());
+
+    assert_eq!(left_left_value.get(&t), Some(&3));
+    assert!(left_left_value.set(&mut t, 30));
+    assert_eq!(
+        t.left.as_ref().unwrap().left.as_ref().unwrap().leaf.value,
+        30
+    );
+
+    let three_deep = left_left_value.clone();
+    t.left.as_mut().unwrap().left = None;
+    assert_eq!(three_deep.get(&t), None);
+
+    let label = left.compose(Tree::acc_leaf()).compose(Leaf::acc_label());
+    assert_eq!(label.get(&t).map(String::as_str), Some("l"));
+}
+
+#[test]
+fn custom_pointers_and_nested_hops() {
+    let mut h = Holder {
+        inner: Handle(Box::new(Box::new(Leaf {
+            value: 1,
+            label: "x".into(),
+        }))),
+    };
+    let value = Holder::acc_inner()
+        .through_deref()
+        .through_deref()
+        .compose(Leaf::acc_value());
+    assert_eq!(value.hop_count(), 2);
+    value.set(&mut h, 9);
+    assert_eq!(h.inner.value, 9);
+
+    // Paths compose with paths
+    let leaf = Holder::acc_inner().through_deref().through_deref();
+    let label = leaf.compose(DerefPath::from(Leaf::acc_label()));
+    label.set_mut(&mut h, |s| s.push('y'));
+    assert_eq!(h.inner.label, "xy");
+}
+
+#[test]
+fn traversal_through_shared_pointers() {
+    #[derive(Accessor)]
+    struct Forest {
+        trees: Vec<Rc<Leaf>>,
+    }
+    let mut f = Forest {
+        trees: vec![
+            Rc::new(Leaf {
+                value: 1,
+                label: "a".into(),
+            }),
+            Rc::new(Leaf {
+                value: 2,
+                label: "b".into(),
+            }),
+        ],
+    };
+    let values = Forest::acc_trees()
+        .each()
+        .through_deref()
+        .compose(Leaf::acc_value());
+    values.map_in_place(&mut f, |v| v * 100);
+    assert_eq!(values.iter(&f).copied().collect::<Vec<_>>(), vec![100, 200]);
+}
+
+#[test]
+fn read_only_paths_cross_non_clone_targets() {
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct Registry {
+        leaf: Box<Leaf>,
+    }
+
+    #[derive(Accessor)]
+    struct Shared {
+        jobs: Arc<Mutex<Vec<u32>>>,
+        registry: Arc<Registry>,
+    }
+
+    let s = Shared {
+        jobs: Arc::new(Mutex::new(vec![1])),
+        registry: Arc::new(Registry {
+            leaf: Box::new(Leaf {
+                value: 7,
+                label: "r".into(),
+            }),
+        }),
+    };
+
+    let jobs: DerefPath<Shared, Mutex<Vec<u32>>, pathmod::ReadOnly> =
+        Shared::acc_jobs().through_deref_ref();
+    jobs.get(&s).lock().unwrap().push(2);
+    assert_eq!(*s.jobs.lock().unwrap(), [1, 2]);
+
+    // Read-only paths keep composing, also through mutable hops.
+    let registry_leaf = pathmod::field!(Registry, leaf);
+    let value = Shared::acc_registry()
+        .through_deref_ref()
+        .compose(registry_leaf)
+        .through_deref()
+        .compose(Leaf::acc_value());
+    assert_eq!(value.hop_count(), 2);
+    assert_eq!(*value.get(&s), 7);
+
+    // Composing with a writable path stays read-only.
+    let label: DerefPath<Shared, String, pathmod::ReadOnly> = Shared::acc_registry()
+        .through_deref_ref()
+        .compose(registry_leaf)
+        .through_deref()
+        .compose(DerefPath::from(Leaf::acc_label()));
+    assert_eq!(label.get(&s), "r");
+}
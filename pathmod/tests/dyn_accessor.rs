@@ -0,0 +1,134 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+    age: u32,
+    settings: Settings,
+}
+
+#[derive(Accessor, Debug, Clone, PartialEq)]
+struct Settings {
+    theme: String,
+    volume: u8,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Team {
+    title: String,
+}
+
+fn users() -> Vec<User> {
+    vec![
+        User {
+            name: "carol".into(),
+            age: 41,
+            settings: Settings {
+                theme: "dark".into(),
+                volume: 3,
+            },
+        },
+        User {
+            name: "alice".into(),
+            age: 29,
+            settings: Settings {
+                theme: "light".into(),
+                volume: 9,
+            },
+        },
+    ]
+}
+
+#[test]
+fn mixed_field_types_in_one_map() {
+    let columns: HashMap<&str, DynAccessor<User>> = [
+        ("name", User::acc_name().into()),
+        ("age", User::acc_age().into()),
+        (
+            "volume",
+            User::acc_settings().compose(Settings::acc_volume()).into(),
+        ),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut u = users().remove(0);
+    let volume = columns["volume"];
+    assert_eq!(volume.field_type_id(), TypeId::of::<u8>());
+    assert_eq!(volume.field_type_name(), "u8");
+    assert_eq!(volume.root_type_id(), TypeId::of::<User>());
+    assert_eq!(volume.get_any(&u).downcast_ref::<u8>(), Some(&3));
+
+    *volume.get_any_mut(&mut u).downcast_mut::<u8>().unwrap() += 1;
+    assert_eq!(u.settings.volume, 4);
+
+    assert!(columns["age"].set_any(&mut u, Box::new(42u32)).is_ok());
+    assert_eq!(u.age, 42);
+    let rejected = columns["age"].set_any(&mut u, Box::new(1u8)).unwrap_err();
+    assert_eq!(rejected.downcast_ref::<u8>(), Some(&1));
+    assert_eq!(u.age, 42);
+
+    assert!(columns["name"].downcast::<u32>().is_none());
+    let name = columns["name"].downcast::<String>().unwrap();
+    assert_eq!(name.get(&u), "carol");
+}
+
+#[test]
+fn sort_key_chosen_at_runtime() {
+    fn sort_by(rows: &mut [User], key: DynAccessor<User>) {
+        if let Some(acc) = key.downcast::<String>() {
+            rows.sort_by(|a, b| acc.get(a).cmp(acc.get(b)));
+        } else if let Some(acc) = key.downcast::<u32>() {
+            rows.sort_by_key(|r| *acc.get(r));
+        }
+    }
+
+    let mut rows = users();
+    sort_by(&mut rows, DynAccessor::resolve("name").unwrap());
+    assert_eq!(rows[0].name, "alice");
+    sort_by(&mut rows, DynAccessor::resolve("age").unwrap());
+    assert_eq!(rows[0].age, 29);
+}
+
+#[test]
+fn any_accessor_checks_root_and_field_types() {
+    let accessors: Vec<AnyAccessor> = vec![User::acc_age().into(), Team::acc_title().into()];
+
+    let mut user = users().remove(0);
+    let mut team = Team {
+        title: "core".into(),
+    };
+
+    let age = accessors[0];
+    assert_eq!(age.root_type_name(), std::any::type_name::<User>());
+    assert_eq!(age.field_type_id(), TypeId::of::<u32>());
+    assert_eq!(
+        age.get_any(&user as &dyn Any)
+            .and_then(|v| v.downcast_ref::<u32>()),
+        Some(&41)
+    );
+    assert!(age.get_any(&team as &dyn Any).is_none());
+    assert!(age.set_any(&mut team, Box::new(1u32)).is_err());
+
+    let title = accessors[1];
+    assert!(title
+        .set_any(&mut team, Box::new(String::from("infra")))
+        .is_ok());
+    assert_eq!(team.title, "infra");
+    title
+        .get_any_mut(&mut team)
+        .and_then(|v| v.downcast_mut::<String>())
+        .unwrap()
+        .push('!');
+    assert_eq!(team.title, "infra!");
+    assert!(title.get_any_mut(&mut user).is_none());
+
+    assert!(age.downcast::<User, u32>().is_some());
+    assert!(age.downcast::<User, u8>().is_none());
+    assert!(age.downcast::<Team, u32>().is_none());
+    let dyn_age = age.downcast_root::<User>().unwrap();
+    assert_eq!(dyn_age.offset(), User::acc_age().offset());
+}
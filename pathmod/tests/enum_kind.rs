@@ -0,0 +1,58 @@
+use pathmod::prelude::*;
+
+#[derive(EnumAccess, Debug)]
+#[pathmod(kind)]
+enum Msg {
+    Int(i32),
+    Text(String),
+    Quit,
+}
+
+#[derive(EnumAccess)]
+#[pathmod(kind = "Shape")]
+enum Geometry<T> {
+    Point { x: T, y: T },
+    Line(T, T),
+}
+
+#[test]
+fn kind_mirrors_the_variants() {
+    let text = Msg::Text("a".into());
+    assert_eq!(Msg::Int(1).kind(), MsgKind::Int);
+    assert_eq!(text.kind(), MsgKind::Text);
+    assert_eq!(text.as_text().map(String::as_str), Some("a"));
+    assert_eq!(Msg::Quit.kind(), MsgKind::Quit);
+    assert_eq!(MsgKind::ALL, [MsgKind::Int, MsgKind::Text, MsgKind::Quit]);
+
+    let g: Geometry<f32> = Geometry::Line(0.0, 1.0);
+    assert_eq!(g.kind(), Shape::Line);
+    assert_eq!(Geometry::Point { x: 1, y: 2 }.as_point(), Some((&1, &2)));
+    assert_eq!(Shape::ALL.len(), 2);
+}
+
+#[test]
+fn kind_names_round_trip_through_strings() {
+    for kind in MsgKind::ALL {
+        assert_eq!(kind.as_str().parse::<MsgKind>(), Ok(kind));
+        assert_eq!(kind.to_string(), kind.as_str());
+    }
+    assert_eq!(Shape::Point.as_str(), "Point");
+
+    let err = "int".parse::<MsgKind>().unwrap_err();
+    assert_eq!(err.input, "int");
+    assert_eq!(
+        err.to_string(),
+        "unknown variant `int`, expected one of: Int, Text, Quit"
+    );
+}
+
+#[test]
+fn kinds_work_as_map_keys() {
+    let mut counts = std::collections::BTreeMap::new();
+    for m in [Msg::Int(1), Msg::Quit, Msg::Int(2)] {
+        assert!(m.is_quit() || m.as_int().is_some());
+        *counts.entry(m.kind()).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&MsgKind::Int], 2);
+    assert_eq!(counts.get(&MsgKind::Text), None);
+}
@@ -0,0 +1,55 @@
+use pathmod::prelude::*;
+
+#[derive(EnumAccess, Debug, PartialEq, Default)]
+enum Slot {
+    #[default]
+    Empty,
+    #[pathmod(from)]
+    Text(String),
+    #[pathmod(from)]
+    Number {
+        value: i64,
+    },
+    Pair(u8, u8),
+}
+
+#[test]
+fn into_moves_the_payload_out_or_returns_self() {
+    assert_eq!(Slot::Text("hi".into()).into_text(), Ok(String::from("hi")));
+    assert_eq!(Slot::Pair(1, 2).into_pair(), Ok((1, 2)));
+    assert_eq!(Slot::Empty.into_empty(), Ok(()));
+    assert_eq!(Slot::Pair(1, 2).into_text(), Err(Slot::Pair(1, 2)));
+}
+
+#[test]
+fn take_leaves_the_default_variant_behind() {
+    let mut s = Slot::Text("owned".into());
+    assert_eq!(s.take_number(), None);
+    assert_eq!(s, Slot::Text("owned".into()));
+    assert_eq!(s.take_text(), Some(String::from("owned")));
+    assert_eq!(s, Slot::Empty);
+
+    let mut p = Slot::Pair(3, 4);
+    assert_eq!(p.take_pair(), Some((3, 4)));
+    assert!(p.is_empty());
+}
+
+#[test]
+fn replace_switches_variant_and_returns_the_old_value() {
+    let mut s = Slot::Number { value: 7 };
+    let old = s.replace_text(String::from("new"));
+    assert_eq!(old, Slot::Number { value: 7 });
+    assert_eq!(s.replace_pair(1, 2), Slot::Text("new".into()));
+    assert_eq!(s.replace_empty(), Slot::Pair(1, 2));
+    assert_eq!(s, Slot::Empty);
+}
+
+#[test]
+fn from_and_try_from_for_opted_in_variants() {
+    assert_eq!(Slot::from(String::from("a")), Slot::Text("a".into()));
+    let n: Slot = 5i64.into();
+    assert_eq!(n, Slot::Number { value: 5 });
+
+    assert_eq!(i64::try_from(n), Ok(5));
+    assert_eq!(String::try_from(Slot::Pair(0, 1)), Err(Slot::Pair(0, 1)));
+}
@@ -0,0 +1,62 @@
+use pathmod::prelude::*;
+
+#[derive(EnumAccess, Debug, PartialEq)]
+enum Msg {
+    Int(i32),
+    Text(String),
+    Pos(Pos),
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Pos {
+    line: u32,
+    col: u32,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Envelope {
+    seq: u64,
+    msg: Msg,
+}
+
+#[test]
+fn derived_prism_composes_with_accessors() {
+    let mut env = Envelope {
+        seq: 1,
+        msg: Msg::Text("hi".into()),
+    };
+
+    let text = Envelope::acc_msg().compose_prism(Msg::prism_text());
+    assert_eq!(text.get(&env).map(String::as_str), Some("hi"));
+    assert!(text.set_mut(&mut env, |s| s.push('!')));
+    assert_eq!(env.msg, Msg::Text("hi!".into()));
+
+    // Prism -> Accessor direction
+    let col = Envelope::acc_msg()
+        .compose_prism(Msg::prism_pos())
+        .compose(Pos::acc_col());
+    assert_eq!(col.get(&env), None);
+    assert!(!col.set(&mut env, 3));
+
+    Msg::prism_pos().set(env.msg_mut(), Pos { line: 1, col: 2 });
+    assert!(col.set(&mut env, 3));
+    assert_eq!(env.msg, Msg::Pos(Pos { line: 1, col: 3 }));
+    assert_eq!(env.seq, 1);
+
+    let int = Msg::prism_int();
+    assert_eq!(int.review(4), Msg::Int(4));
+}
+
+impl Envelope {
+    fn msg_mut(&mut self) -> &mut Msg {
+        Envelope::acc_msg().get_mut(self)
+    }
+}
+
+const INT: Prism<Msg, i32> = Msg::prism_int();
+
+#[test]
+fn derived_prism_is_const() {
+    let m = Msg::Int(2);
+    assert_eq!(INT.preview(&m), Some(&2));
+}
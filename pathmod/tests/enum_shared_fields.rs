@@ -0,0 +1,98 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, PartialEq, Clone)]
+struct Meta {
+    author: String,
+    revision: u32,
+}
+
+#[derive(EnumAccess, Debug, PartialEq)]
+enum Event {
+    Created {
+        id: u64,
+        meta: Meta,
+        payload: String,
+    },
+    Renamed {
+        from: String,
+        id: u64,
+        meta: Meta,
+    },
+    Deleted {
+        id: u64,
+        meta: Meta,
+        hard: bool,
+    },
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Envelope {
+    seq: u32,
+    event: Event,
+}
+
+fn created() -> Event {
+    Event::Created {
+        id: 1,
+        meta: Meta {
+            author: "ann".into(),
+            revision: 1,
+        },
+        payload: "p".into(),
+    }
+}
+
+#[test]
+fn shared_fields_are_total_accessors() {
+    let mut e = created();
+    assert_eq!(*e.id(), 1);
+    *e.id_mut() = 2;
+
+    e.set_deleted(7, e.meta().clone(), true);
+    assert_eq!(*e.id(), 7);
+    assert_eq!(e.meta().author, "ann");
+    assert_eq!(e.as_deleted_hard(), Some(&true));
+}
+
+#[test]
+fn lenses_work_in_every_variant() {
+    let id = Event::lens_id();
+    let mut e = Event::Renamed {
+        from: "a".into(),
+        id: 3,
+        meta: Meta {
+            author: "bo".into(),
+            revision: 1,
+        },
+    };
+    assert_eq!(*id.get(&e), 3);
+    id.set(&mut e, 4);
+    assert_eq!(*e.id(), 4);
+    assert_eq!(e.as_renamed_from().map(String::as_str), Some("a"));
+
+    let mut c = created();
+    id.set_mut(&mut c, |v| *v += 10);
+    assert_eq!(*id.get(&c), 11);
+    assert!(c.as_created_payload().is_some());
+}
+
+#[test]
+fn lenses_compose_with_struct_accessors() {
+    // Into a struct field behind the lens...
+    let revision = Event::lens_meta().compose(Meta::acc_revision());
+    // ...and from a struct field into the lens.
+    let env_revision = Envelope::acc_event().compose_lens(revision);
+
+    let mut env = Envelope {
+        seq: 9,
+        event: created(),
+    };
+    env_revision.set_mut(&mut env, |r| *r += 1);
+    assert_eq!(*env_revision.get(&env), 2);
+    assert_eq!(env.event.meta().revision, 2);
+    assert_eq!(env.seq, 9);
+
+    // Totality survives conversion to an optional.
+    let opt: Optional<Envelope, u32> = env_revision.into();
+    assert_eq!(opt.get(&env), Some(&2));
+}
@@ -0,0 +1,112 @@
+use pathmod::prelude::*;
+
+#[derive(EnumAccess, Debug, PartialEq)]
+enum Event {
+    Quit,
+    Resize(u32, u32),
+    Key { code: u16, shift: bool },
+    Paste { text: String },
+    Nothing(),
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Log {
+    last: Event,
+}
+
+#[test]
+fn unit_variants_have_is_set_and_a_unit_prism() {
+    let mut e = Event::Resize(1, 2);
+    assert!(!e.is_quit());
+    e.set_quit();
+    assert_eq!(e, Event::Quit);
+
+    let quit = Event::prism_quit();
+    assert_eq!(quit.preview(&e), Some(&()));
+    assert!(quit.preview(&Event::Resize(1, 1)).is_none());
+    assert_eq!(quit.review(()), Event::Quit);
+
+    e.set_nothing();
+    assert!(e.is_nothing());
+}
+
+#[test]
+fn multi_field_tuple_variants() {
+    let mut e = Event::Resize(80, 24);
+    assert!(e.is_resize());
+    assert_eq!(e.as_resize(), Some((&80, &24)));
+    assert_eq!(e.as_resize_1(), Some(&24));
+
+    if let Some((w, h)) = e.as_resize_mut() {
+        core::mem::swap(w, h);
+    }
+    *e.as_resize_0_mut().unwrap() += 1;
+    e.map_resize(|w, h| *h *= *w);
+    assert_eq!(e, Event::Resize(25, 2000));
+
+    e.set_resize(1, 1);
+    assert_eq!(e, Event::Resize(1, 1));
+    assert!(e.as_key().is_none() && e.as_key_code().is_none());
+}
+
+#[test]
+fn struct_like_variants() {
+    let mut e = Event::Key {
+        code: 65,
+        shift: false,
+    };
+    assert_eq!(e.as_key(), Some((&65, &false)));
+    assert_eq!(e.as_key_code(), Some(&65));
+    *e.as_key_shift_mut().unwrap() = true;
+    e.map_key(|code, shift| {
+        if *shift {
+            *code += 32
+        }
+    });
+    assert_eq!(
+        e,
+        Event::Key {
+            code: 97,
+            shift: true
+        }
+    );
+
+    e.set_paste("hello".to_string());
+    assert_eq!(e.as_paste().map(String::as_str), Some("hello"));
+    assert_eq!(e.as_paste_text().map(String::as_str), Some("hello"));
+}
+
+#[test]
+fn single_field_struct_variants_compose_as_prisms() {
+    let mut log = Log { last: Event::Quit };
+    let pasted = Log::acc_last().compose_prism(Event::prism_paste());
+    assert!(pasted.get(&log).is_none());
+    assert!(!pasted.set(&mut log, "x".to_string()));
+
+    Event::prism_paste().set(&mut log.last, "a".to_string());
+    pasted.set_mut(&mut log, |t| t.push('b'));
+    assert_eq!(
+        log.last,
+        Event::Paste {
+            text: "ab".to_string()
+        }
+    );
+}
+
+#[derive(EnumAccess, Debug, PartialEq)]
+enum Response {
+    HttpError(u16),
+    #[pathmod(rename = "ok")]
+    Success {
+        body: String,
+    },
+}
+
+#[test]
+fn variant_names_are_snake_case_or_renamed() {
+    let mut r = Response::HttpError(404);
+    assert!(r.is_http_error() && !r.is_ok());
+    assert_eq!(Response::prism_http_error().preview(&r), Some(&404));
+    r.set_ok(String::from("hi"));
+    assert_eq!(r.as_ok_body().map(String::as_str), Some("hi"));
+}
@@ -0,0 +1,115 @@
+use pathmod::prelude::*;
+use pathmod::{get_many_mut, get_many_mut_or_panic, OverlapError};
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Theme {
+    name: String,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct User {
+    address: Address,
+    theme: Theme,
+    marker: (),
+    visits: Vec<u32>,
+}
+
+fn user() -> User {
+    User {
+        address: Address {
+            city: "Paris".into(),
+            zip: 75001,
+        },
+        theme: Theme {
+            name: "dark".into(),
+        },
+        marker: (),
+        visits: vec![1, 2],
+    }
+}
+
+#[test]
+fn borrows_disjoint_deep_fields_together() {
+    let mut u = user();
+    let city = User::acc_address().compose(Address::acc_city());
+    let name = User::acc_theme().compose(Theme::acc_name());
+
+    let (city, name) = get_many_mut(&mut u, (city, name)).unwrap();
+    core::mem::swap(city, name);
+    assert_eq!(u.address.city, "dark");
+    assert_eq!(u.theme.name, "Paris");
+}
+
+#[test]
+fn mixed_field_types_and_larger_tuples() {
+    let mut u = user();
+    let (zip, name, visits) = get_many_mut(
+        &mut u,
+        (
+            User::acc_address().compose(Address::acc_zip()),
+            User::acc_theme().compose(Theme::acc_name()),
+            User::acc_visits(),
+        ),
+    )
+    .unwrap();
+    *zip += 1;
+    name.push('!');
+    visits.push(*zip);
+    assert_eq!(u.address.zip, 75002);
+    assert_eq!(u.theme.name, "dark!");
+    assert_eq!(u.visits, vec![1, 2, 75002]);
+}
+
+#[test]
+fn overlap_is_reported_with_positions() {
+    let mut u = user();
+    let city = User::acc_address().compose(Address::acc_city());
+    let err = get_many_mut(&mut u, (User::acc_theme(), User::acc_address(), city)).unwrap_err();
+    assert_eq!(
+        err,
+        OverlapError {
+            first: 1,
+            second: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "accessors 1 and 2 focus overlapping fields"
+    );
+
+    // The same accessor twice overlaps with itself.
+    assert!(get_many_mut(&mut u, (User::acc_visits(), User::acc_visits())).is_err());
+}
+
+#[test]
+fn zero_sized_fields_never_overlap() {
+    let mut u = user();
+    let marker = User::acc_marker();
+    assert!(get_many_mut(&mut u, (marker, marker, User::acc_visits())).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "accessors 0 and 1 focus overlapping fields")]
+fn panicking_variant_panics_on_overlap() {
+    let mut u = user();
+    let city = User::acc_address().compose(Address::acc_city());
+    let _ = get_many_mut_or_panic(&mut u, (User::acc_address(), city));
+}
+
+#[test]
+fn splits_root_across_scoped_threads() {
+    let mut u = user();
+    let (address, theme) = get_many_mut_or_panic(&mut u, (User::acc_address(), User::acc_theme()));
+    std::thread::scope(|s| {
+        s.spawn(|| address.city.make_ascii_uppercase());
+        s.spawn(|| theme.name.push_str("-mode"));
+    });
+    assert_eq!(u.address.city, "PARIS");
+    assert_eq!(u.theme.name, "dark-mode");
+}
@@ -0,0 +1,125 @@
+use std::cell::{Cell, RefCell};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread;
+
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, Clone, Copy, PartialEq)]
+struct Pos {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Accessor, Debug, Default)]
+struct Settings {
+    theme: String,
+    volume: u8,
+}
+
+#[derive(Accessor, Debug, Default)]
+struct AppState {
+    settings: Settings,
+    visits: u64,
+}
+
+#[test]
+fn cells_project_to_field_cells() {
+    #[derive(Accessor, Clone, Copy)]
+    struct Player {
+        pos: Pos,
+        score: u32,
+    }
+
+    let p = Cell::new(Player {
+        pos: Pos { x: 1, y: 2 },
+        score: 0,
+    });
+    let x = Player::acc_pos().compose(Pos::acc_x()).get_cell(&p);
+    let score = Player::acc_score().get_cell(&p);
+    x.set(10);
+    score.set(score.get() + 5);
+    assert_eq!(p.get().pos, Pos { x: 10, y: 2 });
+    assert_eq!(p.get().score, 5);
+}
+
+#[test]
+fn refcell_borrows_report_conflicts() {
+    let state = RefCell::new(AppState::default());
+    let theme = AppState::acc_settings().compose(Settings::acc_theme());
+
+    theme.borrow_mut(&state).unwrap().push_str("dark");
+    assert_eq!(*theme.borrow(&state).unwrap(), "dark");
+
+    let reading = AppState::acc_visits().borrow(&state).unwrap();
+    assert!(theme.borrow_mut(&state).is_err());
+    assert!(theme.borrow(&state).is_ok());
+    drop(reading);
+
+    let writing = AppState::acc_visits().borrow_mut(&state).unwrap();
+    assert!(theme.borrow(&state).is_err());
+    drop(writing);
+}
+
+#[test]
+fn rwlock_read_and_exclusive_write() {
+    let state = Arc::new(RwLock::new(AppState::default()));
+    let volume = AppState::acc_settings().compose(Settings::acc_volume());
+
+    volume.write(&*state, 7).unwrap();
+    {
+        let v = volume.read(&state).unwrap();
+        assert_eq!(*v, 7);
+        // Several readers at once.
+        assert_eq!(*AppState::acc_visits().read(&state).unwrap(), 0);
+    }
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let state = Arc::clone(&state);
+            thread::spawn(move || {
+                AppState::acc_visits()
+                    .update_locked(&*state, |v| *v += 1)
+                    .unwrap()
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+    assert_eq!(*AppState::acc_visits().read(&state).unwrap(), 4);
+
+    let mut guard = volume.lock(&*state).unwrap();
+    *guard += 1;
+    drop(guard);
+    assert_eq!(state.read().unwrap().settings.volume, 8);
+}
+
+#[test]
+fn mutex_projection_and_poisoning() {
+    let state = Arc::new(Mutex::new(AppState::default()));
+    let theme = AppState::acc_settings().compose(Settings::acc_theme());
+
+    theme.write(&*state, "light".into()).unwrap();
+    let len = theme.update_locked(&*state, |t| {
+        t.push('!');
+        t.len()
+    });
+    assert_eq!(len.unwrap(), 6);
+    assert_eq!(*theme.lock(&*state).unwrap(), "light!");
+
+    let poisoner = Arc::clone(&state);
+    let _ = thread::spawn(move || {
+        let _guard = poisoner.lock().unwrap();
+        panic!("poison the lock");
+    })
+    .join();
+
+    let err = theme.write(&*state, "dark".into()).unwrap_err();
+    assert_eq!(err.into_inner(), "dark");
+    assert!(AppState::acc_visits()
+        .update_locked(&*state, |_| unreachable!())
+        .is_err());
+    // The mapped guard can still be recovered from a poisoned lock.
+    let guard = theme.lock(&*state).unwrap_or_else(PoisonError::into_inner);
+    assert_eq!(*guard, "light!");
+}
@@ -0,0 +1,124 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, Default, PartialEq)]
+struct Tls {
+    cert: String,
+    port: u16,
+}
+
+#[derive(Accessor, Debug, Default, PartialEq)]
+struct Server {
+    host: String,
+    tls: Option<Tls>,
+}
+
+#[derive(Accessor, Debug, Default, PartialEq)]
+struct Config {
+    server: Option<Server>,
+    retries: Option<u32>,
+    mirrors: Vec<Option<Server>>,
+}
+
+#[test]
+fn some_composes_into_derived_accessors() {
+    let port = Config::acc_server()
+        .some()
+        .compose(Server::acc_tls())
+        .some()
+        .compose(Tls::acc_port());
+
+    let mut c = Config::default();
+    assert_eq!(port.get(&c), None);
+    assert!(!port.set(&mut c, 443));
+    assert_eq!(c, Config::default());
+
+    c.server = Some(Server {
+        host: "h".into(),
+        tls: Some(Tls {
+            cert: "pem".into(),
+            port: 80,
+        }),
+    });
+    assert_eq!(port.get(&c), Some(&80));
+    assert!(port.set(&mut c, 443));
+    assert!(port.set_mut(&mut c, |p| *p += 1));
+    assert_eq!(c.server.as_ref().unwrap().tls.as_ref().unwrap().port, 444);
+
+    // Plain `some` never materializes
+    c.server.as_mut().unwrap().tls = None;
+    assert_eq!(port.get_or_insert(&mut c), None);
+    assert!(c.server.as_ref().unwrap().tls.is_none());
+}
+
+#[test]
+fn some_or_default_materializes_along_the_way() {
+    let cert = Config::acc_server()
+        .some_or_default()
+        .compose(Server::acc_tls())
+        .some_or_default()
+        .compose(Tls::acc_cert());
+
+    let mut c = Config::default();
+    assert_eq!(cert.get(&c), None);
+    *cert.get_or_insert(&mut c).unwrap() = "pem".into();
+    assert_eq!(
+        c.server,
+        Some(Server {
+            host: String::new(),
+            tls: Some(Tls {
+                cert: "pem".into(),
+                port: 0,
+            }),
+        })
+    );
+
+    // Existing values are kept
+    c.server.as_mut().unwrap().host = "kept".into();
+    cert.get_or_insert(&mut c).unwrap().push('!');
+    assert_eq!(c.server.as_ref().unwrap().host, "kept");
+    assert_eq!(cert.get(&c).map(String::as_str), Some("pem!"));
+}
+
+#[test]
+fn get_or_insert_with_on_option_leaves() {
+    let mut c = Config::default();
+    *Config::acc_retries().get_or_insert_with(&mut c, || 3) += 1;
+    assert_eq!(c.retries, Some(4));
+
+    let tls = Config::acc_server()
+        .some_or_default()
+        .compose(Server::acc_tls());
+    let t = tls
+        .get_or_insert_with(&mut c, || Tls {
+            cert: "x".into(),
+            port: 1,
+        })
+        .unwrap();
+    t.port = 2;
+    assert_eq!(c.server.unwrap().tls.unwrap().port, 2);
+}
+
+#[test]
+fn traversal_some_skips_none() {
+    let mut c = Config {
+        mirrors: vec![
+            Some(Server {
+                host: "a".into(),
+                tls: None,
+            }),
+            None,
+            Some(Server {
+                host: "b".into(),
+                tls: None,
+            }),
+        ],
+        ..Config::default()
+    };
+    let hosts = Config::acc_mirrors()
+        .each()
+        .some()
+        .compose(Server::acc_host());
+    assert_eq!(hosts.count(&c), 2);
+    hosts.for_each_mut(&mut c, |h| h.make_ascii_uppercase());
+    assert_eq!(hosts.iter(&c).cloned().collect::<Vec<_>>(), ["A", "B"]);
+}
@@ -0,0 +1,81 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor, Clone, Copy)]
+#[repr(C, packed)]
+struct Header {
+    version: u8,
+    length: u32,
+    checksum: u16,
+}
+
+#[derive(Accessor)]
+#[repr(C, packed(2))]
+struct Named {
+    tag: u8,
+    label: String,
+}
+
+#[derive(Accessor)]
+struct Frame {
+    seq: u64,
+    header: Header,
+}
+
+fn header() -> Header {
+    Header {
+        version: 1,
+        length: 512,
+        checksum: 0xBEEF,
+    }
+}
+
+#[test]
+fn reads_and_writes_unaligned_fields_by_value() {
+    let mut h = header();
+    let length: UnalignedAccessor<Header, u32> = Header::acc_length();
+    assert_eq!(length.offset(), 1);
+    assert_eq!(length.read(&h), 512);
+
+    length.write(&mut h, 1024);
+    Header::acc_checksum().update(&mut h, |c| c ^ 0xFFFF);
+    assert_eq!({ h.length }, 1024);
+    assert_eq!({ h.checksum }, 0x4110);
+    assert_eq!(Header::acc_version().replace(&mut h, 2), 1);
+    assert_eq!({ h.version }, 2);
+}
+
+#[test]
+fn non_copy_fields_are_moved_in_and_out() {
+    let mut n = Named {
+        tag: 7,
+        label: "old".to_string(),
+    };
+    let old = Named::acc_label().replace(&mut n, "new".to_string());
+    assert_eq!(old, "old");
+    Named::acc_label().write(&mut n, "newer".to_string());
+    let n = n.with_tag(8);
+    assert_eq!(Named::acc_tag().read(&n), 8);
+    let Named { label, .. } = n;
+    assert_eq!(label, "newer");
+}
+
+#[test]
+fn composes_from_aligned_roots_into_packed_structs() {
+    let mut f = Frame {
+        seq: 9,
+        header: header(),
+    };
+    const LENGTH: UnalignedAccessor<Frame, u32> =
+        Frame::acc_header().compose_unaligned(Header::acc_length());
+    LENGTH.update(&mut f, |l| l + 1);
+    assert_eq!(LENGTH.read(&f), 513);
+    assert_eq!(Frame::acc_seq().unaligned().read(&f), 9);
+    // The packed struct itself has alignment 1, so it can still be borrowed whole.
+    assert_eq!({ Frame::acc_header().get(&f).length }, 513);
+}
+
+#[test]
+fn packed_structs_have_no_field_table() {
+    assert!(DynAccessor::<Frame>::resolve("header").is_ok());
+    assert!(DynAccessor::<Frame>::resolve("header.length").is_err());
+}
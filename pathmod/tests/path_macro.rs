@@ -0,0 +1,65 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Profile {
+    address: Address,
+    tags: (u8, Pair),
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Pair(i16, i64);
+
+#[derive(Accessor, Debug, PartialEq)]
+struct User {
+    id: u64,
+    profile: Profile,
+}
+
+const CITY: Accessor<User, String> = pathmod::path!(User => profile.address.city);
+
+fn user() -> User {
+    User {
+        id: 1,
+        profile: Profile {
+            address: Address {
+                city: "berlin".into(),
+                zip: 10115,
+            },
+            tags: (7, Pair(-1, 99)),
+        },
+    }
+}
+
+#[test]
+fn path_macro_matches_composed_accessors() {
+    let composed = User::acc_profile()
+        .compose(Profile::acc_address())
+        .compose(Address::acc_city());
+    assert_eq!(CITY.offset(), composed.offset());
+
+    let mut u = user();
+    CITY.set(&mut u, "Lund".into());
+    assert_eq!(u.profile.address.city, "Lund");
+
+    let zip = pathmod::path!(User => profile.address.zip);
+    zip.set_mut(&mut u, |z| *z += 1);
+    assert_eq!(u.profile.address.zip, 10116);
+}
+
+#[test]
+fn path_macro_supports_tuple_indices() {
+    let mut u = user();
+    let second = pathmod::path!(User => profile.tags.1.1);
+    assert_eq!(*second.get(&u), 99i64);
+    second.set(&mut u, 5);
+    assert_eq!(u.profile.tags.1, Pair(-1, 5));
+
+    let first = pathmod::path!(Pair => 0);
+    assert_eq!(*first.get(&u.profile.tags.1), -1);
+}
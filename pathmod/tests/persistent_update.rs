@@ -0,0 +1,120 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use pathmod::prelude::*;
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A large subtree that counts how often it is cloned.
+#[derive(Accessor, Debug, PartialEq)]
+struct Big(Vec<u32>);
+
+impl Clone for Big {
+    fn clone(&self) -> Self {
+        CLONES.with(|c| c.set(c.get() + 1));
+        Big(self.0.clone())
+    }
+}
+
+fn clones() -> usize {
+    CLONES.with(Cell::get)
+}
+
+#[derive(Accessor, Clone, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(Accessor, Clone, Debug, PartialEq)]
+struct Profile {
+    address: Address,
+    archive: Big,
+}
+
+#[derive(Accessor, Clone, Debug, PartialEq)]
+struct State {
+    profile: Rc<Profile>,
+    history: Rc<Big>,
+    counter: u64,
+}
+
+fn state() -> State {
+    State {
+        profile: Rc::new(Profile {
+            address: Address {
+                city: "berlin".into(),
+                zip: 10115,
+            },
+            archive: Big(vec![1, 2, 3]),
+        }),
+        history: Rc::new(Big(vec![4, 5])),
+        counter: 0,
+    }
+}
+
+#[test]
+fn accessor_updated_leaves_the_original_untouched() {
+    let s = state();
+    let next = State::acc_counter().updated(&s, 7);
+    assert_eq!((s.counter, next.counter), (0, 7));
+
+    let next = State::acc_counter().updated_with(&next, |c| *c += 1);
+    assert_eq!(next.counter, 8);
+    // The plain accessor cloned the root, which only bumped the `Rc`s.
+    assert!(Rc::ptr_eq(&s.profile, &next.profile));
+}
+
+#[test]
+fn deref_path_updated_clones_only_the_spine() {
+    let city = State::acc_profile()
+        .through_deref()
+        .compose(Profile::acc_address())
+        .compose(Address::acc_city());
+
+    let s = state();
+    let before = clones();
+    let next = city.updated(&s, "Lund".into());
+
+    assert_eq!(s.profile.address.city, "berlin");
+    assert_eq!(next.profile.address.city, "Lund");
+    assert_eq!(next.profile.address.zip, 10115);
+    // The profile was shared, so `make_mut` cloned it, including its archive: that is
+    // the spine. The history is off the path and stays shared.
+    assert_eq!(clones() - before, 1);
+    assert!(!Rc::ptr_eq(&s.profile, &next.profile));
+    assert!(Rc::ptr_eq(&s.history, &next.history));
+
+    // Snapshots of snapshots keep sharing everything they did not touch.
+    let later = city.updated_with(&next, |c| c.push('!'));
+    assert_eq!(later.profile.address.city, "Lund!");
+    assert_eq!(next.profile.address.city, "Lund");
+    assert!(Rc::ptr_eq(&s.history, &later.history));
+}
+
+#[derive(Accessor, Clone)]
+struct Shared {
+    data: Arc<Big>,
+    label: String,
+}
+
+#[test]
+fn arc_hops_share_untouched_subtrees() {
+    let label = Shared::acc_label();
+    let s = Shared {
+        data: Arc::new(Big(vec![0; 16])),
+        label: "a".into(),
+    };
+    let before = clones();
+    let next = label.updated(&s, "b".into());
+    assert_eq!(clones(), before);
+    assert!(Arc::ptr_eq(&s.data, &next.data));
+
+    let first = Shared::acc_data().through_deref().compose(Big::acc_0());
+    let next = first.updated_with(&next, |v| v[0] = 9);
+    assert_eq!(clones() - before, 1);
+    assert_eq!((s.data.0[0], next.data.0[0]), (0, 9));
+}
@@ -0,0 +1,125 @@
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll, Waker};
+
+use pathmod::prelude::*;
+
+/// A future that is ready on its second poll and checks that it was not moved in
+/// between, like a self-referential state machine would require.
+#[derive(Default)]
+struct Pinned {
+    addr: Option<usize>,
+    _pin: PhantomPinned,
+}
+
+impl Future for Pinned {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<usize> {
+        // SAFETY: only the `Unpin` field `addr` is written.
+        let this = unsafe { self.get_unchecked_mut() };
+        let here = this as *mut Self as usize;
+        match this.addr {
+            None => {
+                this.addr = Some(here);
+                Poll::Pending
+            }
+            Some(addr) => {
+                assert_eq!(addr, here, "moved while pinned");
+                Poll::Ready(addr)
+            }
+        }
+    }
+}
+
+#[derive(Accessor)]
+struct Counted<F> {
+    #[pathmod(pin)]
+    inner: F,
+    polls: u32,
+}
+
+impl<F: Future> Future for Counted<F> {
+    type Output = (F::Output, u32);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project_pin();
+        *this.polls += 1;
+        match this.inner.poll(cx) {
+            Poll::Ready(out) => Poll::Ready((out, *this.polls)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[derive(Accessor)]
+struct Pair(#[pathmod(pin)] Pinned, String);
+
+#[derive(Accessor)]
+struct Outer {
+    #[pathmod(pin)]
+    pair: Pair,
+}
+
+#[derive(Accessor)]
+struct OnlyUnpinnedIsPinned {
+    #[pathmod(pin)]
+    a: u8,
+    b: PhantomPinned,
+}
+
+fn is_unpin<T: Unpin>() {}
+
+#[test]
+fn project_pin_splits_pinned_and_plain_fields() {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut fut = pin!(Counted {
+        inner: Pinned::default(),
+        polls: 0,
+    });
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    let Poll::Ready((_, polls)) = fut.as_mut().poll(&mut cx) else {
+        panic!("expected the second poll to complete");
+    };
+    assert_eq!(polls, 2);
+}
+
+#[test]
+fn pin_accessors_compose_and_project() {
+    let mut outer = pin!(Outer {
+        pair: Pair(Pinned::default(), "x".into()),
+    });
+    let inner = Outer::pin_pair().compose(Pair::pin_0());
+    let mut cx = Context::from_waker(Waker::noop());
+    assert!(inner.project_pin(outer.as_mut()).poll(&mut cx).is_pending());
+    assert!(inner.get_pin(outer.as_ref()).addr.is_some());
+    assert!(inner.project_pin(outer.as_mut()).poll(&mut cx).is_ready());
+
+    // Unpinned fields are reached with a plain `&mut`, here through the tuple projection.
+    outer.as_mut().project_pin().pair.project_pin().1.push('y');
+    assert_eq!(Outer::acc_pair().compose(Pair::acc_1()).get(&outer), "xy");
+}
+
+#[test]
+fn set_pin_replaces_in_place() {
+    let mut c = pin!(Counted {
+        inner: 1u8,
+        polls: 0,
+    });
+    Counted::pin_inner().set_pin(c.as_mut(), 5);
+    assert_eq!(*Counted::pin_inner().get_pin(c.as_ref()), 5);
+}
+
+#[test]
+fn unpin_follows_the_pinned_fields_only() {
+    is_unpin::<Counted<std::future::Ready<u8>>>();
+    is_unpin::<OnlyUnpinnedIsPinned>();
+    let mut v = OnlyUnpinnedIsPinned {
+        a: 1,
+        b: PhantomPinned,
+    };
+    *Pin::new(&mut v).project_pin().a += 1;
+    let _ = v.b;
+    assert_eq!(v.a, 2);
+}
@@ -73,3 +73,83 @@ fn minimal_clone_reconstruction_bottom_up() {
     assert_eq!(u2.profile.stats.logins, 3);
     assert_eq!(u2.settings.theme.name, "light");
 }
+
+fn user() -> User {
+    User {
+        profile: Profile {
+            address: Address {
+                city: "berlin".into(),
+                zip: 10115,
+            },
+            stats: Stats { logins: 3 },
+        },
+        settings: Settings {
+            theme: Theme {
+                name: "light".into(),
+            },
+        },
+    }
+}
+
+#[test]
+fn replace_and_modify_along_a_composed_path() {
+    let city = User::acc_profile()
+        .compose(Profile::acc_address())
+        .compose(Address::acc_city());
+
+    let u2 = city.replace(user(), "Lund".to_string());
+    assert_eq!(u2.profile.address.city, "Lund");
+    assert_eq!(u2.profile.address.zip, 10115);
+    assert_eq!(u2.profile.stats.logins, 3);
+    assert_eq!(u2.settings.theme.name, "light");
+
+    let u3 = city.modify(u2, |c| c.to_uppercase());
+    assert_eq!(u3.profile.address.city, "LUND");
+    let u4 = User::acc_profile()
+        .compose(Profile::acc_stats())
+        .compose(Stats::acc_logins())
+        .modify(u3, |n| n + 1);
+    assert_eq!(u4.profile.stats.logins, 4);
+}
+
+/// Neither `Clone` nor `Copy`, and counts drops to catch double frees.
+struct Token<'a>(u32, &'a std::cell::Cell<u32>);
+
+impl Drop for Token<'_> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+#[derive(Accessor)]
+struct Vault<'a> {
+    inner: Slot<'a>,
+    label: String,
+}
+
+#[derive(Accessor)]
+struct Slot<'a> {
+    token: Token<'a>,
+}
+
+#[test]
+fn modify_moves_non_clone_values_and_drops_each_once() {
+    let drops = std::cell::Cell::new(0);
+    let token = Vault::acc_inner().compose(Slot::acc_token());
+    let v = Vault {
+        inner: Slot {
+            token: Token(1, &drops),
+        },
+        label: "v".into(),
+    };
+
+    let v = token.modify(v, |t| Token(t.0 + 1, t.1));
+    assert_eq!(drops.get(), 1); // the old token, dropped inside the closure
+    assert_eq!(v.inner.token.0, 2);
+
+    let v = token.replace(v, Token(10, &drops));
+    assert_eq!(drops.get(), 2);
+    assert_eq!((v.inner.token.0, v.label.as_str()), (10, "v"));
+    drop(v);
+    assert_eq!(drops.get(), 3);
+}
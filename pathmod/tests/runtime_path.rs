@@ -0,0 +1,181 @@
+use pathmod::prelude::*;
+use pathmod::PathError;
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Profile {
+    address: Address,
+    nick: String,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct User {
+    id: u64,
+    profile: Profile,
+    pos: Pair,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Pair(i32, Inner);
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Inner(u8, i64);
+
+fn user() -> User {
+    User {
+        id: 1,
+        profile: Profile {
+            address: Address {
+                city: "berlin".into(),
+                zip: 10115,
+            },
+            nick: "n".into(),
+        },
+        pos: Pair(3, Inner(4, 5)),
+    }
+}
+
+#[test]
+fn resolves_named_and_tuple_paths() {
+    let mut u = user();
+
+    let city = DynAccessor::<User>::resolve("profile.address.city").unwrap();
+    assert!(city.is::<String>());
+    let city: Accessor<User, String> = city.downcast().unwrap();
+    city.set(&mut u, "Lund".into());
+    assert_eq!(u.profile.address.city, "Lund");
+
+    let expected = User::acc_profile()
+        .compose(Profile::acc_address())
+        .compose(Address::acc_zip());
+    let zip = DynAccessor::<User>::resolve("profile.address.zip")
+        .unwrap()
+        .try_downcast::<u32>()
+        .unwrap();
+    assert_eq!(zip.offset(), expected.offset());
+
+    let deep = DynAccessor::<User>::resolve("pos.1.1")
+        .unwrap()
+        .try_downcast::<i64>()
+        .unwrap();
+    assert_eq!(*deep.get(&u), 5);
+}
+
+#[test]
+fn reports_descriptive_errors() {
+    let err = DynAccessor::<User>::resolve("profile.adress.city").unwrap_err();
+    assert_eq!(
+        err,
+        PathError::UnknownField {
+            segment: "adress".into(),
+            type_name: core::any::type_name::<Profile>(),
+            available: vec!["address", "nick"],
+        }
+    );
+    assert!(err.to_string().contains("no field `adress`"));
+
+    let err = DynAccessor::<User>::resolve("profile.nick.len").unwrap_err();
+    assert!(matches!(err, PathError::NotAStruct { ref segment, .. } if segment == "len"));
+
+    let err = DynAccessor::<User>::resolve("profile..nick").unwrap_err();
+    assert!(matches!(err, PathError::EmptySegment { .. }));
+    assert!(DynAccessor::<User>::resolve("").is_err());
+
+    let city = DynAccessor::<User>::resolve("profile.address.city").unwrap();
+    assert!(city.downcast::<u32>().is_none());
+    let err = city.try_downcast::<u32>().unwrap_err();
+    assert_eq!(
+        err,
+        PathError::TypeMismatch {
+            expected: "u32",
+            found: core::any::type_name::<String>(),
+        }
+    );
+}
+
+#[test]
+fn field_tables_list_fields_in_order() {
+    let names: Vec<_> = User::FIELDS.iter().map(|f| f.name()).collect();
+    assert_eq!(names, ["id", "profile", "pos"]);
+    assert!(User::FIELDS[0].fields().is_none());
+    assert!(User::FIELDS[1].fields().is_some());
+    assert_eq!(Pair::FIELDS[1].type_name(), core::any::type_name::<Inner>());
+}
+
+mod bank {
+    use pathmod::prelude::*;
+
+    #[derive(Accessor)]
+    pub struct Account {
+        pub owner: String,
+        balance: u64,
+        #[pathmod(vis = "pub")]
+        pub(crate) limit: u64,
+    }
+
+    impl Account {
+        pub fn new(owner: &str, balance: u64) -> Self {
+            Account {
+                owner: owner.into(),
+                balance,
+                limit: 0,
+            }
+        }
+
+        pub fn balance(&self) -> u64 {
+            self.balance
+        }
+    }
+}
+
+#[test]
+fn private_fields_cannot_be_resolved_from_outside() {
+    use bank::Account;
+
+    let err = DynAccessor::<Account>::resolve("balance").unwrap_err();
+    assert_eq!(
+        err,
+        PathError::UnknownField {
+            segment: "balance".into(),
+            type_name: core::any::type_name::<Account>(),
+            available: vec!["owner", "limit"],
+        }
+    );
+
+    let mut acct = Account::new("ada", 10);
+    let owner = DynAccessor::<Account>::resolve("owner")
+        .unwrap()
+        .try_downcast::<String>()
+        .unwrap();
+    owner.set(&mut acct, "bob".into());
+    assert_eq!(acct.owner, "bob");
+    assert_eq!(acct.balance(), 10);
+}
+
+#[derive(Accessor)]
+struct Token {
+    r#type: u8,
+    text: String,
+}
+
+#[test]
+fn raw_identifier_fields_resolve_by_plain_name() {
+    let names: Vec<_> = Token::FIELDS.iter().map(|f| f.name()).collect();
+    assert_eq!(names, ["type", "text"]);
+
+    let mut t = Token {
+        r#type: 1,
+        text: "t".into(),
+    };
+    let ty = DynAccessor::<Token>::resolve("type")
+        .unwrap()
+        .try_downcast::<u8>()
+        .unwrap();
+    ty.set(&mut t, 2);
+    assert_eq!((t.r#type, t.text.as_str()), (2, "t"));
+}
@@ -0,0 +1,124 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Item {
+    name: String,
+    price: u32,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Bag {
+    items: Vec<Item>,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Wrapper {
+    bag: Bag,
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Shop {
+    orders: Vec<Bag>,
+}
+
+#[derive(EnumAccess, Debug, PartialEq)]
+enum Tag {
+    Sale(u32),
+    Note(String),
+}
+
+#[derive(Accessor, Debug, PartialEq)]
+struct Tagged {
+    tags: Vec<Tag>,
+}
+
+fn item(name: &str, price: u32) -> Item {
+    Item {
+        name: name.into(),
+        price,
+    }
+}
+
+#[test]
+fn each_composes_with_element_accessors() {
+    let mut w = Wrapper {
+        bag: Bag {
+            items: vec![item("a", 100), item("b", 250), item("c", 40)],
+        },
+    };
+
+    let prices = Wrapper::acc_bag()
+        .compose(Bag::acc_items())
+        .each()
+        .compose(Item::acc_price());
+
+    assert_eq!(prices.count(&w), 3);
+    assert_eq!(
+        prices.iter(&w).copied().collect::<Vec<_>>(),
+        vec![100, 250, 40]
+    );
+
+    // 10% discount on every line item
+    prices.map_in_place(&mut w, |p| p - p / 10);
+    assert_eq!(
+        prices.iter(&w).copied().collect::<Vec<_>>(),
+        vec![90, 225, 36]
+    );
+
+    prices.for_each_mut(&mut w, |p| *p += 1);
+    let mut total = 0;
+    prices.for_each(&w, |p| total += *p);
+    assert_eq!(total, 91 + 226 + 37);
+
+    // Other fields are untouched
+    let names = Bag::acc_items().each().compose(Item::acc_name());
+    assert_eq!(
+        names.iter(&w.bag).cloned().collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+}
+
+#[test]
+fn nested_each_and_empty_vecs() {
+    let mut shop = Shop {
+        orders: vec![
+            Bag {
+                items: vec![item("a", 10), item("b", 20)],
+            },
+            Bag { items: vec![] },
+            Bag {
+                items: vec![item("c", 30)],
+            },
+        ],
+    };
+
+    let all_prices = Shop::acc_orders()
+        .each()
+        .compose(Bag::acc_items())
+        .each()
+        .compose(Item::acc_price());
+
+    assert_eq!(all_prices.count(&shop), 3);
+    all_prices.map_in_place(&mut shop, |p| p * 2);
+    assert_eq!(
+        all_prices.iter(&shop).copied().collect::<Vec<_>>(),
+        vec![20, 40, 60]
+    );
+
+    let empty = Shop { orders: vec![] };
+    assert_eq!(all_prices.count(&empty), 0);
+}
+
+#[test]
+fn each_composes_with_prisms_by_skipping_mismatches() {
+    let mut t = Tagged {
+        tags: vec![Tag::Sale(5), Tag::Note("x".into()), Tag::Sale(7)],
+    };
+    let sales = Tagged::acc_tags().each().compose(Tag::prism_sale());
+    assert_eq!(sales.count(&t), 2);
+    sales.for_each_mut(&mut t, |v| *v *= 10);
+    assert_eq!(
+        t.tags,
+        vec![Tag::Sale(50), Tag::Note("x".into()), Tag::Sale(70)]
+    );
+}
@@ -0,0 +1,150 @@
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+use pathmod::prelude::*;
+
+/// Records its name in a shared log when dropped.
+struct Noisy(&'static str, Rc<RefCell<Vec<&'static str>>>);
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+#[derive(Accessor)]
+#[pathmod(uninit)]
+struct Header {
+    id: u32,
+    name: String,
+}
+
+#[derive(Accessor)]
+#[pathmod(uninit)]
+struct Frame {
+    header: Header,
+    payload: [u8; 64 * 1024],
+    tags: Vec<String>,
+}
+
+#[derive(Accessor)]
+#[pathmod(uninit)]
+struct Three(Noisy, Noisy, Noisy);
+
+#[derive(Accessor)]
+#[pathmod(uninit)]
+struct Slot<T> {
+    value: T,
+    generation: u64,
+}
+
+#[derive(Accessor, Debug)]
+#[pathmod(uninit)]
+struct Token {
+    r#type: u8,
+    r#ref: u16,
+}
+
+#[test]
+fn raw_identifier_fields_use_their_plain_names() {
+    let mut b = UninitBuilder::<Token>::new();
+    b.write(Token::init_type(), 1);
+    assert_eq!(b.missing().collect::<Vec<_>>(), ["ref"]);
+    b.write(Token::init_ref(), 2);
+    let t = b.try_build().expect("every field is written");
+    assert_eq!((t.r#type, t.r#ref), (1, 2));
+}
+
+#[test]
+fn builds_only_once_every_field_is_written() {
+    let mut b = UninitBuilder::<Frame>::new();
+    b.write(
+        Frame::init_header(),
+        Header {
+            id: 1,
+            name: "h".into(),
+        },
+    );
+    assert!(b.is_written(Frame::init_header()) && !b.is_written(Frame::init_tags()));
+    assert_eq!(b.missing().collect::<Vec<_>>(), ["payload", "tags"]);
+
+    let mut b = b.try_build().err().expect("payload and tags are missing");
+    b.uninit_mut(Frame::init_payload()).write([7; 64 * 1024]);
+    // SAFETY: the payload was just written in full.
+    unsafe { b.assume_written(Frame::init_payload()) };
+    b.write(Frame::init_tags(), vec!["a".into()]);
+    b.get_mut(Frame::init_tags()).unwrap().push("b".into());
+
+    let frame = b.try_build_boxed().expect("every field is written");
+    assert_eq!((frame.header.id, frame.header.name.as_str()), (1, "h"));
+    assert_eq!(frame.payload[64 * 1024 - 1], 7);
+    assert_eq!(frame.tags, ["a", "b"]);
+}
+
+#[test]
+fn dropping_half_built_drops_exactly_the_written_fields() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let noisy = |name| Noisy(name, Rc::clone(&log));
+
+    let mut b = UninitBuilder::<Three>::new();
+    b.write(Three::init_0(), noisy("a"));
+    b.write(Three::init_2(), noisy("c"));
+    // Overwriting drops the previous value.
+    b.write(Three::init_2(), noisy("c2"));
+    assert_eq!(*log.borrow(), ["c"]);
+    assert!(format!("{b:?}").contains("missing: [\"1\"]"));
+
+    drop(b);
+    assert_eq!(*log.borrow(), ["c", "a", "c2"]);
+
+    log.borrow_mut().clear();
+    let three = UninitBuilder::<Three>::new()
+        .with(Three::init_0(), noisy("x"))
+        .with(Three::init_1(), noisy("y"))
+        .with(Three::init_2(), noisy("z"))
+        .try_build()
+        .expect("every field is written");
+    assert!(log.borrow().is_empty());
+    drop(three);
+    assert_eq!(*log.borrow(), ["x", "y", "z"]);
+}
+
+#[test]
+fn generic_structs_and_field_names() {
+    let slot = UninitBuilder::<Slot<String>>::new()
+        .with(Slot::init_generation(), 3)
+        .with(Slot::init_value(), "v".to_string())
+        .try_build()
+        .expect("every field is written");
+    assert_eq!((slot.value.as_str(), slot.generation), ("v", 3));
+    assert_eq!(Slot::<String>::init_generation().name(), "generation");
+    assert_eq!(Three::init_1().accessor(), Three::acc_1());
+}
+
+#[test]
+fn project_ptr_initializes_nested_fields_in_place() {
+    let mut frame = Box::<Frame>::new_uninit();
+    let root = frame.as_mut_ptr();
+    let header = Frame::acc_header();
+    // SAFETY: every leaf field is written exactly once before `assume_init`.
+    let frame = unsafe {
+        header.compose(Header::acc_id()).project_ptr(root).write(9);
+        header
+            .compose(Header::acc_name())
+            .project_ptr(root)
+            .write("n".into());
+        Frame::acc_payload().project_ptr(root).write_bytes(0, 1);
+        Frame::acc_tags().project_ptr(root).write(Vec::new());
+        frame.assume_init()
+    };
+    assert_eq!((frame.header.id, frame.header.name.as_str()), (9, "n"));
+
+    let mut header = MaybeUninit::<Header>::uninit();
+    Header::acc_id().project_uninit(&mut header).write(4);
+    Header::acc_name()
+        .project_uninit(&mut header)
+        .write(String::new());
+    // SAFETY: both fields were written.
+    assert_eq!(unsafe { header.assume_init() }.id, 4);
+}
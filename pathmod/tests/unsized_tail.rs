@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use pathmod::prelude::*;
+
+#[derive(Accessor)]
+#[repr(C)]
+struct Packet {
+    len: u16,
+    data: [u8],
+}
+
+#[derive(Accessor)]
+#[repr(C)]
+struct RawPacket<const N: usize> {
+    len: u16,
+    data: [u8; N],
+}
+
+#[derive(Accessor)]
+struct Labeled<T: ?Sized> {
+    tag: u8,
+    value: T,
+}
+
+#[derive(Accessor)]
+struct Wrapped<T>(u32, T)
+where
+    T: ?Sized;
+
+#[derive(Accessor)]
+#[repr(C)]
+struct Buf(u16, [u8]);
+
+#[derive(Accessor)]
+struct Name {
+    id: u32,
+    text: str,
+}
+
+/// View a sized packet as the unsized `Packet` with the same prefix.
+fn as_packet<const N: usize>(raw: &mut RawPacket<N>) -> &mut Packet {
+    let ptr = core::ptr::slice_from_raw_parts_mut(raw as *mut RawPacket<N> as *mut u8, N);
+    // SAFETY: both types are `repr(C)` with the same prefix, and the slice length
+    // matches the array length.
+    unsafe { &mut *(ptr as *mut Packet) }
+}
+
+#[test]
+fn slice_tail_keeps_the_root_length() {
+    let mut raw = RawPacket {
+        len: 3,
+        data: [1, 2, 3],
+    };
+    let packet = as_packet(&mut raw);
+
+    let data: TailAccessor<Packet, [u8]> = Packet::acc_data();
+    assert_eq!(data.get(packet), &[1, 2, 3]);
+    data.set_mut(packet, |d| d.reverse());
+    // Sized fields of an unsized root are ordinary accessors.
+    let len: Accessor<Packet, u16> = Packet::acc_len();
+    len.set_mut(packet, |l| *l += 1);
+
+    assert_eq!(raw.data, [3, 2, 1]);
+    assert_eq!(RawPacket::<3>::acc_len().get(&raw), &4);
+}
+
+#[test]
+fn dyn_tail_follows_the_vtable() {
+    let mut small: Box<Labeled<dyn Display>> = Box::new(Labeled { tag: 1, value: 7u8 });
+    let wide: Box<Labeled<dyn Display>> = Box::new(Labeled {
+        tag: 2,
+        value: 1.5f64,
+    });
+
+    let value = Labeled::<dyn Display>::acc_value();
+    assert_eq!(value.get(&small).to_string(), "7");
+    assert_eq!(value.get(&wide).to_string(), "1.5");
+    Labeled::<dyn Display>::acc_tag().set(&mut small, 9);
+    assert_eq!(small.tag, 9);
+}
+
+#[test]
+fn sized_instantiations_still_work() {
+    let l = Labeled {
+        tag: 0,
+        value: String::from("a"),
+    }
+    .with_tag(5);
+    let value = Labeled::<String>::acc_value();
+    let mut l = l;
+    value.set(&mut l, "b".into());
+    assert_eq!((l.tag, l.value.as_str()), (5, "b"));
+
+    let mut w: Box<Wrapped<[u16]>> = Box::new(Wrapped(1, [10, 20]));
+    Wrapped::<[u16]>::acc_1().get_mut(&mut w)[1] += 1;
+    assert_eq!(Wrapped::<[u16]>::acc_0().get(&w), &1);
+    assert_eq!(w.1, [10, 21]);
+}
+
+#[test]
+fn str_tail_is_a_tail_accessor() {
+    let text: TailAccessor<Name, str> = Name::acc_text();
+    let id: Accessor<Name, u32> = Name::acc_id();
+    let _ = (text, id);
+}
+
+#[test]
+fn tuple_struct_slice_tail() {
+    let mut raw = RawPacket {
+        len: 2,
+        data: [5, 6],
+    };
+    let ptr = core::ptr::slice_from_raw_parts_mut(&mut raw as *mut RawPacket<2> as *mut u8, 2);
+    // SAFETY: `Buf` is `repr(C)` with the same prefix as `RawPacket`, and the slice
+    // length matches the array length.
+    let buf = unsafe { &mut *(ptr as *mut Buf) };
+
+    let tail: TailAccessor<Buf, [u8]> = Buf::acc_1();
+    tail.set_mut(buf, |d| d[0] = 7);
+    Buf::acc_0().set(buf, 1);
+    assert_eq!(tail.get(buf), &[7, 6]);
+    assert_eq!((raw.len, raw.data), (1, [7, 6]));
+}
@@ -0,0 +1,213 @@
+use core::sync::atomic::Ordering;
+
+use crate::Accessor;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The standard atomic types, whose fields accessors can operate on through a shared
+/// `&T` root.
+///
+/// Implemented for `AtomicBool`, the atomic integers and `AtomicPtr`, where the
+/// target supports them. The trait is sealed; its methods are reached through
+/// [`Accessor::load`] and friends.
+pub trait Atomic: sealed::Sealed {
+    /// The plain value held by the atomic, e.g. `u64` for `AtomicU64`.
+    type Value: Copy;
+
+    #[doc(hidden)]
+    fn load(&self, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn store(&self, value: Self::Value, order: Ordering);
+    #[doc(hidden)]
+    fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn compare_exchange(
+        &self,
+        current: Self::Value,
+        new: Self::Value,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::Value, Self::Value>;
+}
+
+/// The atomic integer types, which also support arithmetic and bitwise updates.
+pub trait AtomicInteger: Atomic {
+    #[doc(hidden)]
+    fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn fetch_max(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn fetch_min(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn fetch_and(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn fetch_or(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn fetch_xor(&self, value: Self::Value, order: Ordering) -> Self::Value;
+}
+
+macro_rules! impl_atomic {
+    ($atomic:ty => $value:ty $(; $($generics:ident),*)?) => {
+        impl$(<$($generics),*>)? sealed::Sealed for $atomic {}
+
+        impl$(<$($generics),*>)? Atomic for $atomic {
+            type Value = $value;
+
+            fn load(&self, order: Ordering) -> $value {
+                <$atomic>::load(self, order)
+            }
+            fn store(&self, value: $value, order: Ordering) {
+                <$atomic>::store(self, value, order)
+            }
+            fn swap(&self, value: $value, order: Ordering) -> $value {
+                <$atomic>::swap(self, value, order)
+            }
+            fn compare_exchange(
+                &self,
+                current: $value,
+                new: $value,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$value, $value> {
+                <$atomic>::compare_exchange(self, current, new, success, failure)
+            }
+        }
+    };
+}
+
+macro_rules! impl_atomic_integer {
+    ($($width:literal: $atomic:ident => $value:ty),+ $(,)?) => {$(
+        #[cfg(target_has_atomic = $width)]
+        impl_atomic!(core::sync::atomic::$atomic => $value);
+
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicInteger for core::sync::atomic::$atomic {
+            fn fetch_add(&self, value: $value, order: Ordering) -> $value {
+                core::sync::atomic::$atomic::fetch_add(self, value, order)
+            }
+            fn fetch_sub(&self, value: $value, order: Ordering) -> $value {
+                core::sync::atomic::$atomic::fetch_sub(self, value, order)
+            }
+            fn fetch_max(&self, value: $value, order: Ordering) -> $value {
+                core::sync::atomic::$atomic::fetch_max(self, value, order)
+            }
+            fn fetch_min(&self, value: $value, order: Ordering) -> $value {
+                core::sync::atomic::$atomic::fetch_min(self, value, order)
+            }
+            fn fetch_and(&self, value: $value, order: Ordering) -> $value {
+                core::sync::atomic::$atomic::fetch_and(self, value, order)
+            }
+            fn fetch_or(&self, value: $value, order: Ordering) -> $value {
+                core::sync::atomic::$atomic::fetch_or(self, value, order)
+            }
+            fn fetch_xor(&self, value: $value, order: Ordering) -> $value {
+                core::sync::atomic::$atomic::fetch_xor(self, value, order)
+            }
+        }
+    )+};
+}
+
+#[cfg(target_has_atomic = "8")]
+impl_atomic!(core::sync::atomic::AtomicBool => bool);
+#[cfg(target_has_atomic = "ptr")]
+impl_atomic!(core::sync::atomic::AtomicPtr<P> => *mut P; P);
+
+impl_atomic_integer! {
+    "8": AtomicI8 => i8,
+    "8": AtomicU8 => u8,
+    "16": AtomicI16 => i16,
+    "16": AtomicU16 => u16,
+    "32": AtomicI32 => i32,
+    "32": AtomicU32 => u32,
+    "64": AtomicI64 => i64,
+    "64": AtomicU64 => u64,
+    "ptr": AtomicIsize => isize,
+    "ptr": AtomicUsize => usize,
+}
+
+/// Atomic operations on a focused atomic field, through a shared root.
+///
+/// ```rust
+/// use core::sync::atomic::{AtomicU64, Ordering};
+/// use pathmod_core::field;
+///
+/// struct Counters { hits: AtomicU64, misses: AtomicU64 }
+/// let c = Counters { hits: AtomicU64::new(0), misses: AtomicU64::new(0) };
+///
+/// let hits = field!(Counters, hits);
+/// hits.fetch_add(&c, 2, Ordering::Relaxed);
+/// assert_eq!(hits.load(&c, Ordering::Relaxed), 2);
+/// assert_eq!(hits.compare_exchange(&c, 2, 5, Ordering::AcqRel, Ordering::Acquire), Ok(2));
+/// assert_eq!(c.misses.load(Ordering::Relaxed), 0);
+/// ```
+impl<T, A: Atomic> Accessor<T, A> {
+    /// Load the focused atomic, as [`AtomicU64::load`](core::sync::atomic::AtomicU64::load).
+    pub fn load(&self, root: &T, order: Ordering) -> A::Value {
+        self.get(root).load(order)
+    }
+
+    /// Store into the focused atomic.
+    pub fn store(&self, root: &T, value: A::Value, order: Ordering) {
+        self.get(root).store(value, order)
+    }
+
+    /// Store into the focused atomic, returning the previous value.
+    pub fn swap(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).swap(value, order)
+    }
+
+    /// Store `new` if the focused atomic holds `current`; returns the previous value,
+    /// as `Ok` if it was `current`.
+    pub fn compare_exchange(
+        &self,
+        root: &T,
+        current: A::Value,
+        new: A::Value,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<A::Value, A::Value> {
+        self.get(root)
+            .compare_exchange(current, new, success, failure)
+    }
+}
+
+impl<T, A: AtomicInteger> Accessor<T, A> {
+    /// Add to the focused atomic (wrapping), returning the previous value.
+    pub fn fetch_add(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).fetch_add(value, order)
+    }
+
+    /// Subtract from the focused atomic (wrapping), returning the previous value.
+    pub fn fetch_sub(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).fetch_sub(value, order)
+    }
+
+    /// Store the maximum of the focused atomic and `value`, returning the previous value.
+    pub fn fetch_max(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).fetch_max(value, order)
+    }
+
+    /// Store the minimum of the focused atomic and `value`, returning the previous value.
+    pub fn fetch_min(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).fetch_min(value, order)
+    }
+
+    /// Bitwise "and" into the focused atomic, returning the previous value.
+    pub fn fetch_and(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).fetch_and(value, order)
+    }
+
+    /// Bitwise "or" into the focused atomic, returning the previous value.
+    pub fn fetch_or(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).fetch_or(value, order)
+    }
+
+    /// Bitwise "xor" into the focused atomic, returning the previous value.
+    pub fn fetch_xor(&self, root: &T, value: A::Value, order: Ordering) -> A::Value {
+        self.get(root).fetch_xor(value, order)
+    }
+}
@@ -0,0 +1,105 @@
+use core::fmt;
+use core::mem::{align_of, size_of};
+
+use crate::Accessor;
+
+/// Error returned by [`Accessor::from_offset_checked`] for an offset that cannot
+/// belong to a field of the requested type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetError {
+    /// The field would not lie entirely within the root.
+    OutOfBounds {
+        /// The rejected offset.
+        offset: isize,
+        /// Size of the field type.
+        field_size: usize,
+        /// Size of the root type.
+        root_size: usize,
+    },
+    /// The field would not be aligned for its type.
+    Misaligned {
+        /// The rejected offset.
+        offset: isize,
+        /// Alignment of the field type.
+        field_align: usize,
+        /// Alignment of the root type.
+        root_align: usize,
+    },
+}
+
+impl fmt::Display for OffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OffsetError::OutOfBounds {
+                offset,
+                field_size,
+                root_size,
+            } => write!(
+                f,
+                "field of {field_size} bytes at offset {offset} does not fit in a root of {root_size} bytes"
+            ),
+            OffsetError::Misaligned {
+                offset,
+                field_align,
+                root_align,
+            } => write!(
+                f,
+                "offset {offset} is not aligned to {field_align} in a root aligned to {root_align}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OffsetError {}
+
+impl<T, F> Accessor<T, F> {
+    /// Construct from a byte offset after checking that an `F` there would fit inside
+    /// `T` and be aligned.
+    ///
+    /// This catches offsets that are out of bounds or misaligned, which
+    /// [`from_offset`](Self::from_offset) would silently accept. It cannot check that
+    /// the bytes at `offset` really hold an `F`, so prefer [`field!`](crate::field)
+    /// or the derive, which need no `unsafe` at all.
+    ///
+    /// ```rust
+    /// use pathmod_core::{Accessor, OffsetError};
+    ///
+    /// #[repr(C)]
+    /// struct Pair { a: u16, b: u32 }
+    ///
+    /// let b = unsafe { Accessor::<Pair, u32>::from_offset_checked(4) }.unwrap();
+    /// assert_eq!(*b.get(&Pair { a: 1, b: 2 }), 2);
+    /// assert!(matches!(
+    ///     unsafe { Accessor::<Pair, u32>::from_offset_checked(2) },
+    ///     Err(OffsetError::Misaligned { .. })
+    /// ));
+    /// assert!(matches!(
+    ///     unsafe { Accessor::<Pair, u32>::from_offset_checked(8) },
+    ///     Err(OffsetError::OutOfBounds { .. })
+    /// ));
+    /// ```
+    ///
+    /// # Safety
+    /// If this returns `Ok`, the offset must still lead to a field of type `F` in
+    /// every `T`, as required by [`from_offset`](Self::from_offset).
+    pub const unsafe fn from_offset_checked(offset: isize) -> Result<Self, OffsetError> {
+        let (field_size, root_size) = (size_of::<F>(), size_of::<T>());
+        if offset < 0 || offset as usize > root_size || root_size - (offset as usize) < field_size {
+            return Err(OffsetError::OutOfBounds {
+                offset,
+                field_size,
+                root_size,
+            });
+        }
+        let (field_align, root_align) = (align_of::<F>(), align_of::<T>());
+        if root_align < field_align || !(offset as usize).is_multiple_of(field_align) {
+            return Err(OffsetError::Misaligned {
+                offset,
+                field_align,
+                root_align,
+            });
+        }
+        // SAFETY: bounds and alignment were checked; the rest is up to the caller.
+        Ok(unsafe { Accessor::from_offset(offset) })
+    }
+}
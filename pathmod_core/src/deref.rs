@@ -0,0 +1,504 @@
+use core::marker::PhantomData;
+use core::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::optional::{RawGet, RawGetMut};
+use crate::{Accessor, Optional, Traversal};
+
+/// Smart pointers that a [`DerefPath`] can read through.
+///
+/// Reads go through [`Deref`]. Implemented for `Box`, `Rc` and `Arc` with any target,
+/// so read-only paths cross `Arc<Mutex<_>>` and other non-`Clone` targets; see
+/// [`DerefHopMut`] for writes. Implement both for your own pointers to let paths cross
+/// them:
+///
+/// ```rust
+/// use core::ops::{Deref, DerefMut};
+/// use pathmod_core::{DerefHop, DerefHopMut};
+///
+/// struct Handle<T>(Box<T>);
+/// impl<T> Deref for Handle<T> {
+///     type Target = T;
+///     fn deref(&self) -> &T { &self.0 }
+/// }
+/// impl<T> DerefMut for Handle<T> {
+///     fn deref_mut(&mut self) -> &mut T { &mut self.0 }
+/// }
+/// impl<T> DerefHop for Handle<T> {}
+/// impl<T> DerefHopMut for Handle<T> {
+///     fn deref_hop_mut(&mut self) -> &mut T { self }
+/// }
+/// ```
+pub trait DerefHop: Deref {}
+
+/// Smart pointers that a [`DerefPath`] can also write through.
+///
+/// Writes go through [`deref_hop_mut`](Self::deref_hop_mut), which for unique owners
+/// is simply `DerefMut` and for shared owners is a copy-on-write `make_mut`, so
+/// `Rc<U>` and `Arc<U>` implement it only for `U: Clone`.
+pub trait DerefHopMut: DerefHop {
+    /// Borrow the target mutably for a write through a path.
+    fn deref_hop_mut(&mut self) -> &mut Self::Target;
+}
+
+impl<U: ?Sized> DerefHop for Box<U> {}
+
+impl<U: ?Sized> DerefHop for Rc<U> {}
+
+impl<U: ?Sized> DerefHop for Arc<U> {}
+
+impl<U: ?Sized> DerefHopMut for Box<U> {
+    fn deref_hop_mut(&mut self) -> &mut U {
+        self
+    }
+}
+
+impl<U: Clone> DerefHopMut for Rc<U> {
+    /// Copy-on-write: clones the target first if it is shared.
+    fn deref_hop_mut(&mut self) -> &mut U {
+        Rc::make_mut(self)
+    }
+}
+
+impl<U: Clone> DerefHopMut for Arc<U> {
+    /// Copy-on-write: clones the target first if it is shared.
+    fn deref_hop_mut(&mut self) -> &mut U {
+        Arc::make_mut(self)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Whether a [`DerefPath`] can write: [`ReadWrite`] or [`ReadOnly`]. Sealed.
+pub trait PathKind: sealed::Sealed {
+    /// The kind of a path composed of one of this kind and one of kind `N`.
+    type Join<N: PathKind>: PathKind;
+}
+
+/// Marker for [`DerefPath`]s whose every hop is a [`DerefHopMut`]: they can read and
+/// write. The default.
+#[derive(Debug)]
+pub enum ReadWrite {}
+
+/// Marker for [`DerefPath`]s with at least one read-only [`DerefHop`]: they can only
+/// read. Built by [`through_deref_ref`](DerefPath::through_deref_ref).
+#[derive(Debug)]
+pub enum ReadOnly {}
+
+impl sealed::Sealed for ReadWrite {}
+impl sealed::Sealed for ReadOnly {}
+
+impl PathKind for ReadWrite {
+    type Join<N: PathKind> = N;
+}
+
+impl PathKind for ReadOnly {
+    type Join<N: PathKind> = ReadOnly;
+}
+
+/// One pointer hop: the offset to a smart pointer within the current allocation and
+/// monomorphized functions that follow it. Read-only hops only appear in
+/// [`ReadOnly`] paths, whose `deref_mut` is never called.
+#[derive(Clone, Copy)]
+struct Hop {
+    offset: isize,
+    deref: unsafe fn(*const ()) -> *const (),
+    deref_mut: unsafe fn(*mut ()) -> *mut (),
+}
+
+unsafe fn deref_raw<P: DerefHop>(ptr: *const ()) -> *const ()
+where
+    P::Target: Sized,
+{
+    // SAFETY: the caller passes a pointer to a live `P`.
+    let p = unsafe { &*(ptr as *const P) };
+    &**p as *const P::Target as *const ()
+}
+
+unsafe fn deref_raw_mut<P: DerefHopMut>(ptr: *mut ()) -> *mut ()
+where
+    P::Target: Sized,
+{
+    // SAFETY: the caller passes a pointer to a live `P` it has exclusive access to.
+    let p = unsafe { &mut *(ptr as *mut P) };
+    p.deref_hop_mut() as *mut P::Target as *mut ()
+}
+
+unsafe fn no_deref_mut(_: *mut ()) -> *mut () {
+    unreachable!("write through a read-only DerefPath hop")
+}
+
+fn walk(hops: &[Hop], tail: isize, mut ptr: *const ()) -> *const () {
+    for hop in hops {
+        // SAFETY: each hop's offset leads to the pointer it was built for.
+        ptr = unsafe { (hop.deref)(ptr.byte_offset(hop.offset)) };
+    }
+    // SAFETY: `tail` is an accessor offset within the last allocation.
+    unsafe { ptr.byte_offset(tail) }
+}
+
+fn walk_mut(hops: &[Hop], tail: isize, mut ptr: *mut ()) -> *mut () {
+    for hop in hops {
+        // SAFETY: as in `walk`.
+        ptr = unsafe { (hop.deref_mut)(ptr.byte_offset(hop.offset)) };
+    }
+    // SAFETY: as in `walk`.
+    unsafe { ptr.byte_offset(tail) }
+}
+
+/// A total focus on a field `F` inside `T` that may cross pointer indirections.
+///
+/// An [`Accessor`] is a single offset and cannot leave the root's allocation. A
+/// `DerefPath` is a sequence of offset runs separated by deref hops through
+/// [`DerefHop`] pointers such as `Box`, `Rc` and `Arc`. Offsets within one run are
+/// still added together when composing, so only the hops cost anything extra.
+///
+/// Writes through `Rc`/`Arc` use `make_mut`: shared targets are cloned before they
+/// are modified, so other owners never observe the change. Paths through targets
+/// that cannot be cloned, such as `Arc<Mutex<_>>`, are built with
+/// [`through_deref_ref`](Self::through_deref_ref) and are [`ReadOnly`].
+///
+/// ```rust
+/// use pathmod_core::{Accessor, DerefPath};
+///
+/// struct Node { value: i32, next: Box<Leaf> }
+/// struct Leaf { value: i32 }
+///
+/// let next: Accessor<Node, Box<Leaf>> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Node, next) as isize) };
+/// let leaf_value: Accessor<Leaf, i32> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Leaf, value) as isize) };
+///
+/// let path: DerefPath<Node, i32> = next.through_deref().compose(leaf_value);
+/// let mut n = Node { value: 1, next: Box::new(Leaf { value: 2 }) };
+/// path.set_mut(&mut n, |v| *v += 40);
+/// assert_eq!(n.next.value, 42);
+/// ```
+pub struct DerefPath<T, F, M = ReadWrite> {
+    hops: Vec<Hop>,
+    /// Offset of `F` within the allocation reached after the last hop.
+    tail: isize,
+    _phantom: PhantomData<fn(T) -> (F, M)>,
+}
+
+impl<T, F, M> Clone for DerefPath<T, F, M> {
+    fn clone(&self) -> Self {
+        Self {
+            hops: self.hops.clone(),
+            tail: self.tail,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F, M> core::fmt::Debug for DerefPath<T, F, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let offsets: Vec<isize> = self.hops.iter().map(|h| h.offset).collect();
+        f.debug_struct("DerefPath")
+            .field("hops", &offsets)
+            .field("tail", &self.tail)
+            .finish()
+    }
+}
+
+impl<T, F, M> DerefPath<T, F, M> {
+    /// Number of pointer hops along this path.
+    pub fn hop_count(&self) -> usize {
+        self.hops.len()
+    }
+
+    /// Borrow the focused field immutably.
+    pub fn get<'a>(&self, root: &'a T) -> &'a F {
+        let ptr = walk(&self.hops, self.tail, root as *const T as *const ());
+        // SAFETY: the walk follows valid offsets and live pointers owned by `root`.
+        unsafe { &*(ptr as *const F) }
+    }
+
+    /// Forget that this path can write.
+    pub fn read_only(self) -> DerefPath<T, F, ReadOnly> {
+        DerefPath {
+            hops: self.hops,
+            tail: self.tail,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Compose this path with a further one, yielding a path from `T` to `V`.
+    ///
+    /// `next` may be an [`Accessor`] or another [`DerefPath`]; accessor offsets are
+    /// folded into the current run. The result is [`ReadOnly`] if either part is.
+    pub fn compose<V, N>(self, next: impl Into<DerefPath<F, V, N>>) -> DerefPath<T, V, M::Join<N>>
+    where
+        M: PathKind,
+        N: PathKind,
+    {
+        self.join(next.into())
+    }
+
+    fn join<V, N, K>(self, next: DerefPath<F, V, N>) -> DerefPath<T, V, K> {
+        let mut hops = self.hops;
+        let tail = match next.hops.split_first() {
+            None => self.tail + next.tail,
+            Some((first, rest)) => {
+                hops.push(Hop {
+                    offset: self.tail + first.offset,
+                    ..*first
+                });
+                hops.extend_from_slice(rest);
+                next.tail
+            }
+        };
+        DerefPath {
+            hops,
+            tail,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> DerefPath<T, F> {
+    /// Borrow the focused field mutably.
+    ///
+    /// Shared `Rc`/`Arc` hops along the way are made unique first (copy-on-write).
+    pub fn get_mut<'a>(&self, root: &'a mut T) -> &'a mut F {
+        let ptr = walk_mut(&self.hops, self.tail, root as *mut T as *mut ());
+        // SAFETY: as in `get`, and every hop was taken through `deref_hop_mut`.
+        unsafe { &mut *(ptr as *mut F) }
+    }
+
+    /// Set by moving a new value into the focused location.
+    pub fn set(&self, root: &mut T, value: F) {
+        *self.get_mut(root) = value;
+    }
+
+    /// Mutate the focused location in-place using the provided closure.
+    pub fn set_mut(&self, root: &mut T, f: impl FnOnce(&mut F)) {
+        f(self.get_mut(root));
+    }
+
+    /// Set by cloning the provided value into the focused location.
+    ///
+    /// Only `F: Clone` is required (plus whatever `Clone` bound the `Rc`/`Arc` hops
+    /// on the path already carry).
+    pub fn set_clone(&self, root: &mut T, value: &F)
+    where
+        F: Clone,
+    {
+        *self.get_mut(root) = value.clone();
+    }
+
+    /// Return an updated copy of `root` with the focused field set to `value`, leaving
+    /// `root` untouched.
+    ///
+    /// Only the spine is cloned: the root itself (`T: Clone`), and each `Rc`/`Arc`
+    /// target along the path, which `make_mut` finds shared with `root`. Subtrees off
+    /// the path stay shared between both versions, so with the large parts of a state
+    /// behind `Arc` a snapshot costs a few reference-count bumps instead of a deep
+    /// clone. The `Clone` bounds are those of the hops on the path (`U: Clone` for
+    /// every `Rc<U>`/`Arc<U>`), plus `T: Clone`; `F` need not be `Clone`.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use pathmod_core::{field, DerefPath};
+    ///
+    /// #[derive(Clone)]
+    /// struct Settings { theme: String }
+    /// #[derive(Clone)]
+    /// struct State { settings: Arc<Settings>, log: Arc<Vec<String>> }
+    ///
+    /// let theme: DerefPath<State, String> =
+    ///     field!(State, settings).through_deref().compose(field!(Settings, theme));
+    /// let state = State { settings: Arc::new(Settings { theme: "light".into() }), log: Arc::default() };
+    /// let next = theme.updated(&state, "dark".into());
+    ///
+    /// assert_eq!((state.settings.theme.as_str(), next.settings.theme.as_str()), ("light", "dark"));
+    /// assert!(Arc::ptr_eq(&state.log, &next.log)); // unchanged subtree is shared
+    /// ```
+    pub fn updated(&self, root: &T, value: F) -> T
+    where
+        T: Clone,
+    {
+        let mut next = root.clone();
+        self.set(&mut next, value);
+        next
+    }
+
+    /// Like [`updated`](Self::updated), but mutates the focused field of the copy in
+    /// place.
+    pub fn updated_with(&self, root: &T, f: impl FnOnce(&mut F)) -> T
+    where
+        T: Clone,
+    {
+        let mut next = root.clone();
+        f(self.get_mut(&mut next));
+        next
+    }
+}
+
+impl<P: DerefHopMut> DerefPath<P, P::Target>
+where
+    P::Target: Sized,
+{
+    /// The single hop from a pointer to its target.
+    pub(crate) fn hop() -> Self {
+        DerefPath {
+            hops: vec![Hop {
+                offset: 0,
+                deref: deref_raw::<P>,
+                deref_mut: deref_raw_mut::<P>,
+            }],
+            tail: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P: DerefHop> DerefPath<P, P::Target, ReadOnly>
+where
+    P::Target: Sized,
+{
+    /// The single read-only hop from a pointer to its target.
+    fn hop_ref() -> Self {
+        DerefPath {
+            hops: vec![Hop {
+                offset: 0,
+                deref: deref_raw::<P>,
+                deref_mut: no_deref_mut,
+            }],
+            tail: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, P: DerefHopMut, M> DerefPath<T, P, M>
+where
+    P::Target: Sized,
+{
+    /// Continue through the focused pointer to its target.
+    pub fn through_deref(self) -> DerefPath<T, P::Target, M> {
+        self.join(DerefPath::<P, P::Target>::hop())
+    }
+}
+
+impl<T, P: DerefHop, M> DerefPath<T, P, M>
+where
+    P::Target: Sized,
+{
+    /// Continue through the focused pointer to its target for reading only. Unlike
+    /// [`through_deref`](Self::through_deref) this needs no [`DerefHopMut`], so it
+    /// crosses `Arc<Mutex<_>>` and other targets that cannot be cloned.
+    pub fn through_deref_ref(self) -> DerefPath<T, P::Target, ReadOnly> {
+        self.join(DerefPath::<P, P::Target, ReadOnly>::hop_ref())
+    }
+}
+
+impl<T, P: DerefHopMut> Accessor<T, P>
+where
+    P::Target: Sized,
+{
+    /// Continue through the pointer at this accessor to its target.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use pathmod_core::Accessor;
+    ///
+    /// struct App { config: Arc<String> }
+    /// let config: Accessor<App, Arc<String>> =
+    ///     unsafe { Accessor::from_offset(core::mem::offset_of!(App, config) as isize) };
+    ///
+    /// let shared = Arc::new("a".to_string());
+    /// let mut app = App { config: Arc::clone(&shared) };
+    /// config.through_deref().set(&mut app, "b".to_string());
+    /// assert_eq!(*app.config, "b");
+    /// assert_eq!(*shared, "a"); // copy-on-write left the other owner untouched
+    /// ```
+    pub fn through_deref(self) -> DerefPath<T, P::Target> {
+        DerefPath::from(self).through_deref()
+    }
+}
+
+impl<T, P: DerefHop> Accessor<T, P>
+where
+    P::Target: Sized,
+{
+    /// Continue through the pointer at this accessor to its target for reading only.
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use pathmod_core::{field, DerefPath, ReadOnly};
+    ///
+    /// struct App { jobs: Arc<Mutex<Vec<u32>>> }
+    ///
+    /// let jobs: DerefPath<App, Mutex<Vec<u32>>, ReadOnly> =
+    ///     field!(App, jobs).through_deref_ref();
+    /// let app = App { jobs: Arc::new(Mutex::new(vec![1, 2])) };
+    /// jobs.get(&app).lock().unwrap().push(3);
+    /// assert_eq!(*app.jobs.lock().unwrap(), [1, 2, 3]);
+    /// ```
+    ///
+    /// Read-only paths cannot write:
+    ///
+    /// ```compile_fail
+    /// use std::sync::{Arc, Mutex};
+    /// use pathmod_core::field;
+    ///
+    /// struct App { jobs: Arc<Mutex<Vec<u32>>> }
+    /// let mut app = App { jobs: Arc::new(Mutex::new(vec![])) };
+    /// field!(App, jobs).through_deref_ref().set(&mut app, Mutex::new(vec![1]));
+    /// ```
+    pub fn through_deref_ref(self) -> DerefPath<T, P::Target, ReadOnly> {
+        DerefPath::from(self).through_deref_ref()
+    }
+}
+
+impl<T, P: DerefHopMut> Optional<T, P>
+where
+    P::Target: Sized,
+{
+    /// Continue through the focused pointer to its target.
+    pub fn through_deref(self) -> Optional<T, P::Target> {
+        self.compose(DerefPath::<P, P::Target>::hop())
+    }
+}
+
+impl<T, P: DerefHopMut> Traversal<T, P>
+where
+    P::Target: Sized,
+{
+    /// Continue through every focused pointer to its target.
+    pub fn through_deref(self) -> Traversal<T, P::Target> {
+        self.compose(DerefPath::<P, P::Target>::hop())
+    }
+}
+
+impl<T, F> From<Accessor<T, F>> for DerefPath<T, F> {
+    fn from(acc: Accessor<T, F>) -> Self {
+        DerefPath {
+            hops: Vec::new(),
+            tail: acc.offset(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> From<DerefPath<T, F>> for Optional<T, F> {
+    /// A deref path is an optional focus that is always present.
+    fn from(path: DerefPath<T, F>) -> Self {
+        let hops: Arc<[Hop]> = path.hops.into();
+        let tail = path.tail;
+        let hops_mut = Arc::clone(&hops);
+        let get: Arc<RawGet> = Arc::new(move |root| walk(&hops, tail, root));
+        let get_mut: Arc<RawGetMut> = Arc::new(move |root| walk_mut(&hops_mut, tail, root));
+        Optional::from_raw(get, get_mut)
+    }
+}
+
+impl<T, F> From<DerefPath<T, F>> for Traversal<T, F> {
+    fn from(path: DerefPath<T, F>) -> Self {
+        Optional::from(path).into()
+    }
+}
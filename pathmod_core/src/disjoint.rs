@@ -0,0 +1,210 @@
+use core::fmt;
+use core::mem::size_of;
+
+use crate::Accessor;
+
+/// Error returned by [`get_many_mut`] when two accessors focus overlapping bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlapError {
+    /// Position of the first overlapping accessor in the tuple.
+    pub first: usize,
+    /// Position of the second overlapping accessor in the tuple.
+    pub second: usize,
+}
+
+impl fmt::Display for OverlapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "accessors {} and {} focus overlapping fields",
+            self.first, self.second
+        )
+    }
+}
+
+impl std::error::Error for OverlapError {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A tuple of accessors into the same root `T`, borrowed together by [`get_many_mut`].
+///
+/// Implemented for tuples of one to eight [`Accessor`]s. The trait is sealed: the
+/// disjointness check relies on every element being a real accessor.
+pub trait DisjointAccessors<'a, T>: sealed::Sealed {
+    /// The tuple of mutable borrows, one per accessor.
+    type Output;
+
+    /// Byte range `(offset, size)` of every focused field, in tuple order.
+    #[doc(hidden)]
+    fn regions(&self) -> Vec<(isize, usize)>;
+
+    /// Project every accessor from `root`.
+    ///
+    /// # Safety
+    /// `root` must be valid for `'a` and exclusively borrowed, and the regions must be
+    /// pairwise disjoint.
+    #[doc(hidden)]
+    unsafe fn project(self, root: *mut T) -> Self::Output;
+}
+
+/// Whether two byte ranges `(offset, size)` share at least one byte.
+///
+/// Zero-sized fields occupy no bytes and never overlap anything.
+fn overlap((a, a_len): (isize, usize), (b, b_len): (isize, usize)) -> bool {
+    a_len != 0 && b_len != 0 && a < b + b_len as isize && b < a + a_len as isize
+}
+
+/// Find the first pair of regions that overlap.
+fn check_disjoint(regions: &[(isize, usize)]) -> Result<(), OverlapError> {
+    for (first, &a) in regions.iter().enumerate() {
+        for (second, &b) in regions.iter().enumerate().skip(first + 1) {
+            if overlap(a, b) {
+                return Err(OverlapError { first, second });
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<T, F> Accessor<T, F> {
+    fn region(&self) -> (isize, usize) {
+        (self.offset(), size_of::<F>())
+    }
+
+    /// Whether the field focused by `other` lies entirely within the one focused by
+    /// `self`, e.g. `profile` contains `profile.address.city`.
+    ///
+    /// Every accessor contains itself.
+    pub fn contains<G>(&self, other: &Accessor<T, G>) -> bool {
+        let (a, a_len) = self.region();
+        let (b, b_len) = other.region();
+        a <= b && b + b_len as isize <= a + a_len as isize
+    }
+
+    /// Whether the fields focused by `self` and `other` share at least one byte.
+    ///
+    /// Zero-sized fields never overlap anything.
+    pub fn overlaps<G>(&self, other: &Accessor<T, G>) -> bool {
+        overlap(self.region(), other.region())
+    }
+
+    /// Whether writes through `self` can never affect reads through `other`, and
+    /// vice versa. The opposite of [`overlaps`](Self::overlaps).
+    pub fn is_disjoint<G>(&self, other: &Accessor<T, G>) -> bool {
+        !self.overlaps(other)
+    }
+
+    /// The accessor from `parent`'s field to this one, if `parent` contains it.
+    ///
+    /// This is the inverse of composition: for `full = parent.compose(rest)`,
+    /// `full.strip_prefix(&parent)` returns `rest` when the field is strictly smaller
+    /// than `parent`'s. A field of the same size covers the same bytes as `parent`
+    /// whichever of the two encloses the other, e.g. a newtype and its only field, so
+    /// its position cannot tell that it lies inside `parent` and it is never stripped.
+    /// Zero-sized fields are never stripped either.
+    ///
+    /// ```rust
+    /// use pathmod_core::Accessor;
+    ///
+    /// struct Inner { a: u8, b: u32 }
+    /// struct Outer { x: u64, inner: Inner }
+    /// let inner: Accessor<Outer, Inner> =
+    ///     unsafe { Accessor::from_offset(core::mem::offset_of!(Outer, inner) as isize) };
+    /// let b: Accessor<Inner, u32> =
+    ///     unsafe { Accessor::from_offset(core::mem::offset_of!(Inner, b) as isize) };
+    ///
+    /// let full = inner.compose(b);
+    /// assert!(inner.contains(&full));
+    /// assert_eq!(full.strip_prefix(&inner), Some(b));
+    /// ```
+    pub fn strip_prefix<P>(&self, parent: &Accessor<T, P>) -> Option<Accessor<P, F>> {
+        if size_of::<F>() != 0 && size_of::<F>() < size_of::<P>() && parent.contains(self) {
+            // SAFETY: `from_offset` requires accessor paths to step only through struct
+            // and tuple fields, and the safe constructors (`#[derive(Accessor)]`,
+            // `path!`, `field!` and composition) reject union fields. Such fields are
+            // either nested or disjoint, never partially overlapping. A field of
+            // non-zero size lying inside `parent`'s field and strictly smaller than it
+            // cannot enclose it, so it was reached through it, and sits at the
+            // difference of the offsets within every `P`.
+            Some(unsafe { Accessor::from_offset(self.offset() - parent.offset()) })
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_disjoint_accessors {
+    ($($f:ident . $idx:tt),+) => {
+        impl<T, $($f),+> sealed::Sealed for ($(Accessor<T, $f>,)+) {}
+
+        impl<'a, T: 'a, $($f: 'a),+> DisjointAccessors<'a, T> for ($(Accessor<T, $f>,)+) {
+            type Output = ($(&'a mut $f,)+);
+
+            fn regions(&self) -> Vec<(isize, usize)> {
+                vec![$(self.$idx.region()),+]
+            }
+
+            unsafe fn project(self, root: *mut T) -> Self::Output {
+                let base = root as *mut u8;
+                // SAFETY: each offset leads to its field inside `root`, and the caller
+                // guarantees the fields do not overlap, so the borrows never alias.
+                unsafe { ($(&mut *(base.offset(self.$idx.offset()) as *mut $f),)+) }
+            }
+        }
+    };
+}
+
+impl_disjoint_accessors!(A.0);
+impl_disjoint_accessors!(A.0, B.1);
+impl_disjoint_accessors!(A.0, B.1, C.2);
+impl_disjoint_accessors!(A.0, B.1, C.2, D.3);
+impl_disjoint_accessors!(A.0, B.1, C.2, D.3, E.4);
+impl_disjoint_accessors!(A.0, B.1, C.2, D.3, E.4, F.5);
+impl_disjoint_accessors!(A.0, B.1, C.2, D.3, E.4, F.5, G.6);
+impl_disjoint_accessors!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7);
+
+/// Borrow several fields of `root` mutably at once.
+///
+/// Each [`Accessor::get_mut`] borrows the whole root, so two deep fields cannot
+/// normally be held as `&mut` together. This checks, from the accessors' offsets and
+/// the fields' sizes, that no two of them share a byte, and then hands out one
+/// borrow per accessor. Fails with an [`OverlapError`] naming the first overlapping
+/// pair, e.g. a field and the struct that contains it.
+///
+/// ```rust
+/// use pathmod_core::{get_many_mut, Accessor};
+///
+/// struct Pos { x: i32, y: i32 }
+/// let x: Accessor<Pos, i32> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Pos, x) as isize) };
+/// let y: Accessor<Pos, i32> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Pos, y) as isize) };
+///
+/// let mut p = Pos { x: 1, y: 2 };
+/// let (x, y) = get_many_mut(&mut p, (x, y)).unwrap();
+/// core::mem::swap(x, y);
+/// assert_eq!((p.x, p.y), (2, 1));
+/// ```
+pub fn get_many_mut<'a, T, A>(root: &'a mut T, accessors: A) -> Result<A::Output, OverlapError>
+where
+    A: DisjointAccessors<'a, T>,
+{
+    check_disjoint(&accessors.regions())?;
+    // SAFETY: `root` is exclusively borrowed for `'a` and the regions were just
+    // checked to be pairwise disjoint.
+    Ok(unsafe { accessors.project(root) })
+}
+
+/// Like [`get_many_mut`], but panics if two accessors overlap.
+#[track_caller]
+pub fn get_many_mut_or_panic<'a, T, A>(root: &'a mut T, accessors: A) -> A::Output
+where
+    A: DisjointAccessors<'a, T>,
+{
+    match get_many_mut(root, accessors) {
+        Ok(borrows) => borrows,
+        Err(e) => panic!("get_many_mut: {e}"),
+    }
+}
@@ -0,0 +1,326 @@
+use core::any::{Any, TypeId};
+use core::marker::PhantomData;
+
+use crate::{Accessor, PathError};
+
+type WriteFn = unsafe fn(*mut (), Box<dyn Any>) -> Result<(), Box<dyn Any>>;
+
+/// Monomorphized operations on an erased type, shared by
+/// [`FieldInfo`](crate::FieldInfo) and the dynamic accessors.
+#[derive(Clone, Copy)]
+pub(crate) struct ErasedType {
+    type_id: fn() -> TypeId,
+    type_name: fn() -> &'static str,
+    as_any: unsafe fn(*const ()) -> *const dyn Any,
+    as_any_mut: unsafe fn(*mut ()) -> *mut dyn Any,
+    write: WriteFn,
+}
+
+unsafe fn as_any<F: 'static>(ptr: *const ()) -> *const dyn Any {
+    ptr as *const F as *const dyn Any
+}
+
+unsafe fn as_any_mut<F: 'static>(ptr: *mut ()) -> *mut dyn Any {
+    ptr as *mut F as *mut dyn Any
+}
+
+unsafe fn write<F: 'static>(ptr: *mut (), value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+    let value = value.downcast::<F>()?;
+    // SAFETY: the caller passes a pointer to a live `F` it has exclusive access to.
+    unsafe { *(ptr as *mut F) = *value };
+    Ok(())
+}
+
+impl ErasedType {
+    pub(crate) const fn of<F: 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<F>,
+            type_name: core::any::type_name::<F>,
+            as_any: as_any::<F>,
+            as_any_mut: as_any_mut::<F>,
+            write: write::<F>,
+        }
+    }
+
+    pub(crate) fn type_id(&self) -> TypeId {
+        (self.type_id)()
+    }
+
+    pub(crate) fn type_name(&self) -> &'static str {
+        (self.type_name)()
+    }
+}
+
+/// An accessor into a root `T` whose field type has been erased.
+///
+/// It records the byte offset together with the field's `TypeId` and type name, so
+/// accessors to fields of different types can share one collection. Values are read
+/// and written as `dyn Any`, and [`downcast`](Self::downcast) recovers the typed
+/// [`Accessor`] once the caller names the right field type. Dynamic accessors are
+/// built from typed ones with `From`, or from runtime string paths with
+/// [`DynAccessor::resolve`].
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use pathmod_core::{Accessor, DynAccessor};
+///
+/// struct Row { name: String, age: u32 }
+/// let name: Accessor<Row, String> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Row, name) as isize) };
+/// let age: Accessor<Row, u32> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Row, age) as isize) };
+///
+/// let columns: HashMap<&str, DynAccessor<Row>> =
+///     [("name", name.into()), ("age", age.into())].into_iter().collect();
+///
+/// let mut row = Row { name: "ada".into(), age: 36 };
+/// let col = columns["age"];
+/// assert_eq!(col.get_any(&row).downcast_ref::<u32>(), Some(&36));
+/// assert!(col.set_any(&mut row, Box::new(37u32)).is_ok());
+/// assert!(col.set_any(&mut row, Box::new("nope")).is_err());
+/// assert_eq!(*col.downcast::<u32>().unwrap().get(&row), 37);
+/// ```
+pub struct DynAccessor<T> {
+    offset: isize,
+    field: ErasedType,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T> Clone for DynAccessor<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DynAccessor<T> {}
+
+impl<T> core::fmt::Debug for DynAccessor<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynAccessor")
+            .field("root_type", &self.root_type_name())
+            .field("field_type", &self.field_type_name())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<T> DynAccessor<T> {
+    /// Assemble from an offset and the erased field type.
+    ///
+    /// # Safety
+    /// `offset` must satisfy the requirements of [`Accessor::from_offset`] for a field
+    /// of the type described by `field`.
+    pub(crate) unsafe fn from_raw_parts(offset: isize, field: ErasedType) -> Self {
+        Self {
+            offset,
+            field,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The byte offset from the start of `T` to the focused field.
+    pub fn offset(&self) -> isize {
+        self.offset
+    }
+
+    /// `TypeId` of the root type.
+    pub fn root_type_id(&self) -> TypeId
+    where
+        T: 'static,
+    {
+        TypeId::of::<T>()
+    }
+
+    /// Name of the root type.
+    pub fn root_type_name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    /// `TypeId` of the erased field type.
+    pub fn field_type_id(&self) -> TypeId {
+        self.field.type_id()
+    }
+
+    /// Name of the erased field type.
+    pub fn field_type_name(&self) -> &'static str {
+        self.field.type_name()
+    }
+
+    /// Whether the erased field type is `F`.
+    pub fn is<F: 'static>(&self) -> bool {
+        self.field_type_id() == TypeId::of::<F>()
+    }
+
+    /// Borrow the focused field as `dyn Any`.
+    pub fn get_any<'a>(&self, root: &'a T) -> &'a dyn Any {
+        // SAFETY: the offset leads to a live field of the erased type inside `root`.
+        unsafe {
+            let ptr = (root as *const T as *const ()).byte_offset(self.offset);
+            &*(self.field.as_any)(ptr)
+        }
+    }
+
+    /// Borrow the focused field mutably as `dyn Any`.
+    pub fn get_any_mut<'a>(&self, root: &'a mut T) -> &'a mut dyn Any {
+        // SAFETY: as in `get_any`, derived from the exclusive borrow of `root`.
+        unsafe {
+            let ptr = (root as *mut T as *mut ()).byte_offset(self.offset);
+            &mut *(self.field.as_any_mut)(ptr)
+        }
+    }
+
+    /// Move `value` into the focused field if it has the field's type.
+    ///
+    /// On a type mismatch `root` is left untouched and `value` is handed back.
+    pub fn set_any(&self, root: &mut T, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        // SAFETY: as in `get_any_mut`; `write` checks the type before writing.
+        unsafe {
+            let ptr = (root as *mut T as *mut ()).byte_offset(self.offset);
+            (self.field.write)(ptr, value)
+        }
+    }
+
+    /// Recover the typed accessor if the field type is `F`.
+    pub fn downcast<F: 'static>(&self) -> Option<Accessor<T, F>> {
+        if self.is::<F>() {
+            // SAFETY: the offset is valid for the field, and its type was just checked.
+            Some(unsafe { Accessor::from_offset(self.offset) })
+        } else {
+            None
+        }
+    }
+
+    /// Like [`downcast`](Self::downcast), but reports the mismatch as a [`PathError`].
+    pub fn try_downcast<F: 'static>(&self) -> Result<Accessor<T, F>, PathError> {
+        self.downcast().ok_or_else(|| PathError::TypeMismatch {
+            expected: core::any::type_name::<F>(),
+            found: self.field_type_name(),
+        })
+    }
+
+    /// Erase the root type as well, yielding an [`AnyAccessor`].
+    pub fn erase(self) -> AnyAccessor
+    where
+        T: 'static,
+    {
+        AnyAccessor {
+            offset: self.offset,
+            root: ErasedType::of::<T>(),
+            field: self.field,
+        }
+    }
+}
+
+impl<T, F: 'static> From<Accessor<T, F>> for DynAccessor<T> {
+    fn from(acc: Accessor<T, F>) -> Self {
+        // SAFETY: the offset comes from a valid accessor to an `F`.
+        unsafe { DynAccessor::from_raw_parts(acc.offset(), ErasedType::of::<F>()) }
+    }
+}
+
+/// A fully type-erased accessor: both the root and the field type are only known at
+/// runtime, through their `TypeId`s.
+///
+/// Every operation first checks that the root passed in has the recorded root type,
+/// so an `AnyAccessor` can be stored alongside accessors for unrelated roots.
+#[derive(Clone, Copy)]
+pub struct AnyAccessor {
+    offset: isize,
+    root: ErasedType,
+    field: ErasedType,
+}
+
+impl core::fmt::Debug for AnyAccessor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnyAccessor")
+            .field("root_type", &self.root_type_name())
+            .field("field_type", &self.field_type_name())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl AnyAccessor {
+    /// `TypeId` of the root type.
+    pub fn root_type_id(&self) -> TypeId {
+        self.root.type_id()
+    }
+
+    /// Name of the root type.
+    pub fn root_type_name(&self) -> &'static str {
+        self.root.type_name()
+    }
+
+    /// `TypeId` of the field type.
+    pub fn field_type_id(&self) -> TypeId {
+        self.field.type_id()
+    }
+
+    /// Name of the field type.
+    pub fn field_type_name(&self) -> &'static str {
+        self.field.type_name()
+    }
+
+    fn root_matches(&self, root: &dyn Any) -> bool {
+        root.type_id() == self.root_type_id()
+    }
+
+    /// Borrow the focused field of `root` as `dyn Any`, if `root` has the root type.
+    pub fn get_any<'a>(&self, root: &'a dyn Any) -> Option<&'a dyn Any> {
+        if !self.root_matches(root) {
+            return None;
+        }
+        // SAFETY: the root type was just checked, and the offset is valid for it.
+        unsafe {
+            let ptr = (root as *const dyn Any as *const ()).byte_offset(self.offset);
+            Some(&*(self.field.as_any)(ptr))
+        }
+    }
+
+    /// Borrow the focused field of `root` mutably, if `root` has the root type.
+    pub fn get_any_mut<'a>(&self, root: &'a mut dyn Any) -> Option<&'a mut dyn Any> {
+        if !self.root_matches(root) {
+            return None;
+        }
+        // SAFETY: as in `get_any`, derived from the exclusive borrow of `root`.
+        unsafe {
+            let ptr = (root as *mut dyn Any as *mut ()).byte_offset(self.offset);
+            Some(&mut *(self.field.as_any_mut)(ptr))
+        }
+    }
+
+    /// Move `value` into the focused field of `root`.
+    ///
+    /// Fails, handing `value` back, if `root` or `value` has the wrong type.
+    pub fn set_any(&self, root: &mut dyn Any, value: Box<dyn Any>) -> Result<(), Box<dyn Any>> {
+        if !self.root_matches(root) {
+            return Err(value);
+        }
+        // SAFETY: as in `get_any_mut`; `write` checks the value type before writing.
+        unsafe {
+            let ptr = (root as *mut dyn Any as *mut ()).byte_offset(self.offset);
+            (self.field.write)(ptr, value)
+        }
+    }
+
+    /// Recover the root-typed [`DynAccessor`] if the root type is `T`.
+    pub fn downcast_root<T: 'static>(&self) -> Option<DynAccessor<T>> {
+        if self.root_type_id() == TypeId::of::<T>() {
+            // SAFETY: the root type was just checked.
+            Some(unsafe { DynAccessor::from_raw_parts(self.offset, self.field) })
+        } else {
+            None
+        }
+    }
+
+    /// Recover the typed accessor if the root type is `T` and the field type is `F`.
+    pub fn downcast<T: 'static, F: 'static>(&self) -> Option<Accessor<T, F>> {
+        self.downcast_root::<T>()?.downcast::<F>()
+    }
+}
+
+impl<T: 'static, F: 'static> From<Accessor<T, F>> for AnyAccessor {
+    fn from(acc: Accessor<T, F>) -> Self {
+        DynAccessor::from(acc).erase()
+    }
+}
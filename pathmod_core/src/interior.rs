@@ -0,0 +1,212 @@
+use core::cell::{BorrowError, BorrowMutError, Cell, Ref, RefCell, RefMut};
+use core::ops::{Deref, DerefMut};
+use std::sync::{
+    LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+
+use crate::Accessor;
+
+/// A lock guard narrowed to one field of the locked value.
+///
+/// Returned by [`Accessor::read`] and [`Accessor::lock`]. The lock is held for as long
+/// as this guard lives; it derefs to the focused field, and mutably so when the
+/// underlying guard is exclusive.
+pub struct MappedGuard<G: Deref, F> {
+    guard: G,
+    acc: Accessor<G::Target, F>,
+}
+
+impl<G: Deref, F> Deref for MappedGuard<G, F> {
+    type Target = F;
+
+    fn deref(&self) -> &F {
+        self.acc.get(&self.guard)
+    }
+}
+
+impl<G: DerefMut, F> DerefMut for MappedGuard<G, F> {
+    fn deref_mut(&mut self) -> &mut F {
+        self.acc.get_mut(&mut self.guard)
+    }
+}
+
+impl<G: Deref, F: core::fmt::Debug> core::fmt::Debug for MappedGuard<G, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// Map the guard of a lock result to a field, keeping the poison flag.
+fn map_guard<G: Deref, F>(
+    result: LockResult<G>,
+    acc: Accessor<G::Target, F>,
+) -> LockResult<MappedGuard<G, F>> {
+    match result {
+        Ok(guard) => Ok(MappedGuard { guard, acc }),
+        Err(poisoned) => Err(PoisonError::new(MappedGuard {
+            guard: poisoned.into_inner(),
+            acc,
+        })),
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Locks that grant exclusive access to a `T`: [`Mutex`] (`lock`) and [`RwLock`]
+/// (`write`). Used by [`Accessor::lock`], [`Accessor::write`] and
+/// [`Accessor::update_locked`].
+///
+/// The trait is sealed.
+pub trait ExclusiveLock<T>: sealed::Sealed {
+    /// The guard of an exclusive lock.
+    type Guard<'a>: DerefMut<Target = T>
+    where
+        Self: 'a;
+
+    /// Block until the lock is held exclusively.
+    #[doc(hidden)]
+    fn lock_exclusive(&self) -> LockResult<Self::Guard<'_>>;
+}
+
+impl<T> sealed::Sealed for Mutex<T> {}
+
+impl<T> ExclusiveLock<T> for Mutex<T> {
+    type Guard<'a>
+        = MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn lock_exclusive(&self) -> LockResult<MutexGuard<'_, T>> {
+        self.lock()
+    }
+}
+
+impl<T> sealed::Sealed for RwLock<T> {}
+
+impl<T> ExclusiveLock<T> for RwLock<T> {
+    type Guard<'a>
+        = RwLockWriteGuard<'a, T>
+    where
+        T: 'a;
+
+    fn lock_exclusive(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        self.write()
+    }
+}
+
+impl<T, F> Accessor<T, F> {
+    /// Project a `&Cell<T>` to a `&Cell<F>` for the focused field, in the style of
+    /// [`Cell::as_slice_of_cells`].
+    ///
+    /// ```rust
+    /// use core::cell::Cell;
+    /// use pathmod_core::field;
+    ///
+    /// struct Pos { x: i32, y: i32 }
+    /// let pos = Cell::new(Pos { x: 1, y: 2 });
+    /// let y = field!(Pos, y).get_cell(&pos);
+    /// y.set(y.get() + 40);
+    /// assert_eq!(pos.into_inner().y, 42);
+    /// ```
+    pub fn get_cell<'a>(&self, cell: &'a Cell<T>) -> &'a Cell<F> {
+        // SAFETY: `Cell<T>` has the same layout as `T`, and `Cell<F>` as `F`, so the
+        // offset leads to the field inside the cell. A `Cell` only allows moving values
+        // in and out on one thread, so a cell of a part of the value is as sound as
+        // a slice of cells (see `Cell::as_slice_of_cells`).
+        unsafe { &*((cell.as_ptr() as *const u8).offset(self.offset()) as *const Cell<F>) }
+    }
+
+    /// Borrow the focused field of a `RefCell<T>`, failing if it is mutably borrowed.
+    pub fn borrow<'a>(&self, cell: &'a RefCell<T>) -> Result<Ref<'a, F>, BorrowError> {
+        let acc = *self;
+        Ok(Ref::map(cell.try_borrow()?, move |root| acc.get(root)))
+    }
+
+    /// Borrow the focused field of a `RefCell<T>` mutably, failing if it is borrowed.
+    ///
+    /// ```rust
+    /// use core::cell::RefCell;
+    /// use pathmod_core::field;
+    ///
+    /// struct Counter { hits: u32 }
+    /// let c = RefCell::new(Counter { hits: 0 });
+    /// *field!(Counter, hits).borrow_mut(&c)? += 1;
+    ///
+    /// let held = c.borrow();
+    /// assert!(field!(Counter, hits).borrow_mut(&c).is_err());
+    /// assert_eq!(held.hits, 1);
+    /// # Ok::<(), core::cell::BorrowMutError>(())
+    /// ```
+    pub fn borrow_mut<'a>(&self, cell: &'a RefCell<T>) -> Result<RefMut<'a, F>, BorrowMutError> {
+        let acc = *self;
+        Ok(RefMut::map(cell.try_borrow_mut()?, move |root| {
+            acc.get_mut(root)
+        }))
+    }
+
+    /// Read-lock `lock` and borrow the focused field.
+    ///
+    /// A poisoned lock is reported as an error that still carries the mapped guard,
+    /// as with [`RwLock::read`].
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, RwLock};
+    /// use pathmod_core::field;
+    ///
+    /// struct AppState { users: Vec<String>, version: u64 }
+    /// let state = Arc::new(RwLock::new(AppState { users: vec![], version: 3 }));
+    ///
+    /// let version = field!(AppState, version);
+    /// assert_eq!(*version.read(&state).unwrap(), 3);
+    /// version.update_locked(&*state, |v| *v += 1).unwrap();
+    /// assert_eq!(*version.read(&state).unwrap(), 4);
+    /// ```
+    pub fn read<'a>(
+        &self,
+        lock: &'a RwLock<T>,
+    ) -> LockResult<MappedGuard<RwLockReadGuard<'a, T>, F>> {
+        map_guard(lock.read(), *self)
+    }
+
+    /// Lock `lock` exclusively (a [`Mutex`], or an [`RwLock`] for writing) and borrow
+    /// the focused field mutably.
+    ///
+    /// A poisoned lock is reported as an error that still carries the mapped guard.
+    pub fn lock<'a, L: ExclusiveLock<T>>(
+        &self,
+        lock: &'a L,
+    ) -> LockResult<MappedGuard<L::Guard<'a>, F>> {
+        map_guard(lock.lock_exclusive(), *self)
+    }
+
+    /// Lock `lock` exclusively and move `value` into the focused field.
+    ///
+    /// If the lock is poisoned nothing is written, and `value` is handed back in the
+    /// error.
+    pub fn write<L: ExclusiveLock<T>>(&self, lock: &L, value: F) -> Result<(), PoisonError<F>> {
+        match self.lock(lock) {
+            Ok(mut field) => {
+                *field = value;
+                Ok(())
+            }
+            Err(_) => Err(PoisonError::new(value)),
+        }
+    }
+
+    /// Lock `lock` exclusively and mutate the focused field in place, returning the
+    /// closure's result.
+    ///
+    /// If the lock is poisoned `f` is not called.
+    pub fn update_locked<L: ExclusiveLock<T>, R>(
+        &self,
+        lock: &L,
+        f: impl FnOnce(&mut F) -> R,
+    ) -> Result<R, PoisonError<()>> {
+        match self.lock(lock) {
+            Ok(mut field) => Ok(f(&mut field)),
+            Err(_) => Err(PoisonError::new(())),
+        }
+    }
+}
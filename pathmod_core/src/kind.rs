@@ -0,0 +1,24 @@
+use core::fmt;
+
+/// Error returned by the `FromStr` impl of a kind enum generated by
+/// `#[derive(EnumAccess)]` with `#[pathmod(kind)]`, for a string that names no variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKindError {
+    /// The string that failed to parse.
+    pub input: String,
+    /// The variant names that would have been accepted.
+    pub expected: &'static [&'static str],
+}
+
+impl fmt::Display for ParseKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown variant `{}`, expected one of: {}",
+            self.input,
+            self.expected.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseKindError {}
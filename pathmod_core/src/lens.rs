@@ -0,0 +1,193 @@
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::optional::{RawGet, RawGetMut};
+use crate::{Accessor, Optional};
+
+/// A total focus on a value `F` inside `T` that is found by matching rather than by a
+/// fixed offset.
+///
+/// The typical example is a field that every variant of an enum has, such as the `id`
+/// of `enum Event { Created { id: u64, .. }, Deleted { id: u64, .. } }`: it is always
+/// present, but its offset differs between variants. `#[derive(EnumAccess)]` generates
+/// `lens_<field>()` for such fields.
+///
+/// A lens is one matching step with [`Accessor`] offsets on either side, so composing
+/// it with accessors (via [`compose`](Self::compose) and [`Accessor::compose_lens`])
+/// stays total, `Copy` and allocation-free. Composing it with partial optics yields an
+/// [`Optional`].
+pub struct Lens<T, F> {
+    /// Offset from `T` to the value matched on.
+    pre: isize,
+    /// The matching functions, erased to `fn()` so that the matched type does not
+    /// appear in `Lens`; they are only called back through `read`/`write`.
+    get: fn(),
+    get_mut: fn(),
+    read: unsafe fn(fn(), *const u8) -> *const u8,
+    write: unsafe fn(fn(), *mut u8) -> *mut u8,
+    /// Offset from the matched field to `F`.
+    post: isize,
+    _phantom: PhantomData<fn(T) -> F>,
+}
+
+impl<T, F> Clone for Lens<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for Lens<T, F> {}
+
+impl<T, F> core::fmt::Debug for Lens<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Lens").finish_non_exhaustive()
+    }
+}
+
+/// Call an erased `fn(&U) -> &G` on a pointer to a `U`.
+unsafe fn read<U, G>(f: fn(), root: *const u8) -> *const u8 {
+    // SAFETY: `f` was erased from exactly this type in `Lens::new`, and `root` points
+    // to a live `U` (see `Lens::get`).
+    let f = unsafe { core::mem::transmute::<fn(), fn(&U) -> &G>(f) };
+    f(unsafe { &*(root as *const U) }) as *const G as *const u8
+}
+
+/// Call an erased `fn(&mut U) -> &mut G` on a pointer to a `U`.
+unsafe fn write<U, G>(f: fn(), root: *mut u8) -> *mut u8 {
+    // SAFETY: as in `read`, with exclusive access.
+    let f = unsafe { core::mem::transmute::<fn(), fn(&mut U) -> &mut G>(f) };
+    f(unsafe { &mut *(root as *mut U) }) as *mut G as *mut u8
+}
+
+impl<T, F> Lens<T, F> {
+    /// Construct a lens from a pair of functions that borrow the focused value.
+    ///
+    /// Both functions must return the same location; they are typically `match`es that
+    /// borrow the same field from every variant.
+    ///
+    /// ```rust
+    /// use pathmod_core::Lens;
+    ///
+    /// enum Shape { Circle { r: f64, name: String }, Square { side: f64, name: String } }
+    ///
+    /// const NAME: Lens<Shape, String> = Lens::new(
+    ///     |s| match s { Shape::Circle { name, .. } | Shape::Square { name, .. } => name },
+    ///     |s| match s { Shape::Circle { name, .. } | Shape::Square { name, .. } => name },
+    /// );
+    ///
+    /// let mut s = Shape::Square { side: 2.0, name: "a".into() };
+    /// NAME.set(&mut s, "b".into());
+    /// assert_eq!(NAME.get(&s), "b");
+    /// ```
+    pub const fn new(get: fn(&T) -> &F, get_mut: fn(&mut T) -> &mut F) -> Self {
+        Self {
+            pre: 0,
+            // SAFETY: function pointers have the same layout; they are only called
+            // back through the matching `read`/`write` instantiation.
+            get: unsafe { core::mem::transmute::<fn(&T) -> &F, fn()>(get) },
+            get_mut: unsafe { core::mem::transmute::<fn(&mut T) -> &mut F, fn()>(get_mut) },
+            read: read::<T, F>,
+            write: write::<T, F>,
+            post: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Borrow the focused value.
+    pub fn get<'a>(&self, root: &'a T) -> &'a F {
+        // SAFETY: `pre` leads to the matched value inside `root`, the matching function
+        // returns a field of it, and `post` leads from there to an `F`.
+        unsafe {
+            let matched = (root as *const T as *const u8).offset(self.pre);
+            &*((self.read)(self.get, matched).offset(self.post) as *const F)
+        }
+    }
+
+    /// Borrow the focused value mutably.
+    pub fn get_mut<'a>(&self, root: &'a mut T) -> &'a mut F {
+        // SAFETY: as in `get`, derived from the exclusive borrow of `root`.
+        unsafe {
+            let matched = (root as *mut T as *mut u8).offset(self.pre);
+            &mut *((self.write)(self.get_mut, matched).offset(self.post) as *mut F)
+        }
+    }
+
+    /// Set by moving a new value into the focused location.
+    pub fn set(&self, root: &mut T, value: F) {
+        *self.get_mut(root) = value;
+    }
+
+    /// Mutate the focused location in-place using the provided closure.
+    pub fn set_mut(&self, root: &mut T, f: impl FnOnce(&mut F)) {
+        f(self.get_mut(root));
+    }
+
+    /// Set by cloning the provided value into the focused location.
+    pub fn set_clone(&self, root: &mut T, value: &F)
+    where
+        F: Clone,
+    {
+        *self.get_mut(root) = value.clone();
+    }
+
+    /// Compose with an accessor into the focused value, yielding a lens from `T` to `V`.
+    pub const fn compose<V>(self, next: Accessor<F, V>) -> Lens<T, V> {
+        Lens {
+            pre: self.pre,
+            get: self.get,
+            get_mut: self.get_mut,
+            read: self.read,
+            write: self.write,
+            post: self.post + next.offset(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Compose with a further focus that may be absent, yielding an optional from `T`
+    /// to `V`.
+    ///
+    /// `next` may be a [`Prism`](crate::Prism) or an [`Optional`].
+    pub fn compose_optional<V>(self, next: impl Into<Optional<F, V>>) -> Optional<T, V> {
+        Optional::from(self).compose(next)
+    }
+}
+
+impl<T, F> Accessor<T, F> {
+    /// Compose this accessor with a lens, yielding a lens from `T` to `V`.
+    pub const fn compose_lens<V>(self, next: Lens<F, V>) -> Lens<T, V> {
+        Lens {
+            pre: self.offset() + next.pre,
+            get: next.get,
+            get_mut: next.get_mut,
+            read: next.read,
+            write: next.write,
+            post: next.post,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> From<Lens<T, F>> for Optional<T, F> {
+    /// Every lens is an optional focus that is always present.
+    fn from(lens: Lens<T, F>) -> Self {
+        let Lens {
+            pre,
+            get,
+            get_mut,
+            read,
+            write,
+            post,
+            ..
+        } = lens;
+        // The captures are plain pointers and offsets, so the closures are `'static`
+        // whatever `T` and `F` are.
+        // SAFETY (both closures): erased pointers point to a live `T`, see `Lens::get`.
+        let get: Arc<RawGet> = Arc::new(move |root: *const ()| unsafe {
+            read(get, (root as *const u8).offset(pre)).offset(post) as *const ()
+        });
+        let get_mut: Arc<RawGetMut> = Arc::new(move |root: *mut ()| unsafe {
+            write(get_mut, (root as *mut u8).offset(pre)).offset(post) as *mut ()
+        });
+        Optional::from_raw(get, get_mut)
+    }
+}
@@ -11,29 +11,69 @@ Highlights
   compute valid offsets for you.
 - Clear clone semantics (MVP): `set_clone` only requires `F: Clone` and does not require
   `T: Clone`, even when composed deeply.
+- By-value rebuilds: `acc.replace(root, value)` and `acc.modify(root, |old| ...)` consume
+  the root and return it updated along a composed path, moving every sibling field and
+  cloning nothing.
+- Interior mutability: `acc.get_cell(&Cell<T>) -> &Cell<F>`, `acc.borrow_mut(&RefCell<T>)`,
+  `acc.read(&RwLock<T>)`, `acc.lock(&Mutex<T>)`, `acc.write(&lock, value)` and
+  `acc.update_locked(&lock, f)` project through the container, reporting borrow
+  conflicts and poisoning as errors.
+- Atomic fields: `Accessor<T, AtomicU64>` (and every other `Atomic*`) offers `load`, `store`,
+  `swap`, `compare_exchange` and `fetch_*` on a shared `&T`, so nested counters are
+  path-addressable.
+- Pin projection: `PinAccessor<T, F>` projects `Pin<&mut T>` to `Pin<&mut F>` for
+  structurally pinned fields; `#[pathmod(pin)]` derives one per field and checks the
+  `Unpin`/`Drop` rules that make it sound.
+- Persistent updates: `acc.updated(&state, value)` returns a new snapshot and leaves
+  `state` untouched; through `Rc`/`Arc` (`DerefPath::updated`) only the spine is cloned
+  and every other subtree stays shared.
+- Const paths: `compose` and `offset` are `const fn`, so
+  `const CITY: Accessor<User, String> = User::acc_profile().compose(...)` needs no
+  runtime setup.
+- Disjoint borrows: `get_many_mut(&mut root, (acc_a, acc_b))` hands out several `&mut`
+  at once after checking from offsets and sizes that the fields do not overlap.
+- Manual accessors: `field!(Type, a.b.0)` builds an accessor for a foreign type from
+  `offset_of!`; `Accessor::from_offset_checked` validates a raw offset's bounds and alignment.
+- Keypaths: `path!(User => profile.address.city)` builds a deep accessor from one nested
+  `offset_of!`, usable in `const` items.
+- Partial focus: `Prism<T, F>` focuses one enum variant's payload; composing it with
+  accessors yields an `Optional<T, F>` whose reads return `Option`. `Option<F>` fields
+  are crossed with `Accessor::some()`.
+- Shared enum fields: `Lens<T, F>` focuses a field every variant has (e.g. an `id`) by
+  matching, and still composes with accessors on either side without allocating.
+- Indirection: `Accessor<T, Box<N>>::through_deref()` yields a `DerefPath<T, N>` that keeps
+  composing across `Box`/`Rc`/`Arc` (copy-on-write) or any `DerefHopMut` pointer;
+  `through_deref_ref()` builds a read-only path through any `DerefHop`, e.g. `Arc<Mutex<_>>`.
+- Runtime paths: types deriving `Accessor` carry a `FieldTable`, so
+  `DynAccessor::<User>::resolve("profile.address.city")` finds a field from a string and
+  `downcast::<String>()` recovers the typed accessor after a `TypeId` check.
+- Type erasure: `DynAccessor<T>` (field type erased) and `AnyAccessor` (root erased too)
+  read and write through `dyn Any`, so accessors of mixed types fit in one collection.
+- Packed structs: `UnalignedAccessor<T, F>` reads and writes fields by value with
+  `read_unaligned`/`write_unaligned` and never forms a reference to them.
+- Unsized tails: the last field of a dynamically sized struct (`[u8]`, `str`, `dyn Trait`)
+  is focused by a `TailAccessor<T, F: ?Sized>` that keeps the root's pointer metadata;
+  sized fields of such roots use ordinary accessors.
+- In-place initialization: `acc.project_ptr(*mut T) -> *mut F` writes fields of a
+  `MaybeUninit<T>`; `UninitBuilder<T>` (for `#[pathmod(uninit)]` structs) tracks written
+  fields, yields `T` only once all are set, and drops exactly those written if abandoned.
+- Bulk focus: `Accessor<T, Vec<E>>::each()` yields a `Traversal<T, E>` over every element,
+  which composes further with element accessors.
 
 Quick example
 ```rust
-use pathmod_core::Accessor;
+use pathmod_core::{field, Accessor};
 
 #[derive(Debug, PartialEq)]
 struct Bar { x: i32 }
 #[derive(Debug, PartialEq)]
 struct Foo { a: i32, b: Bar }
 
-fn acc_b() -> Accessor<Foo, Bar> {
-    fn get_ref(f: &Foo) -> &Bar { &f.b }
-    fn get_mut(f: &mut Foo) -> &mut Bar { &mut f.b }
-    Accessor::from_fns(get_ref, get_mut)
-}
-fn acc_x() -> Accessor<Bar, i32> {
-    fn get_ref(b: &Bar) -> &i32 { &b.x }
-    fn get_mut(b: &mut Bar) -> &mut i32 { &mut b.x }
-    Accessor::from_fns(get_ref, get_mut)
-}
+const ACC_B: Accessor<Foo, Bar> = field!(Foo, b);
+const ACC_X: Accessor<Bar, i32> = field!(Bar, x);
 
 let mut foo = Foo { a: 1, b: Bar { x: 2 } };
-let acc = acc_b().compose(acc_x());
+let acc = ACC_B.compose(ACC_X);
 acc.set_mut(&mut foo, |v| *v += 5);
 assert_eq!(foo.b.x, 7);
 ```
@@ -41,25 +81,109 @@ assert_eq!(foo.b.x, 7);
 Safety notes
 - Internally, accessors are represented by a byte offset and use unsafe pointer arithmetic
   to project fields. The public API is safe when accessors are constructed by the provided
-  derive macros or `from_fns`.
+  derive macros or the `field!`/`path!` macros, which compute offsets with `offset_of!`.
 
 "#]
 
 use core::marker::PhantomData;
 
+mod atomic;
+mod checked;
+mod deref;
+mod disjoint;
+mod dynamic;
+mod interior;
+mod kind;
+mod lens;
+mod macros;
+mod optional;
+mod pin;
+mod prism;
+mod rebuild;
+mod reflect;
+mod tail;
+mod traversal;
+mod unaligned;
+mod uninit;
+
+pub use atomic::{Atomic, AtomicInteger};
+pub use checked::OffsetError;
+pub use deref::{DerefHop, DerefHopMut, DerefPath, PathKind, ReadOnly, ReadWrite};
+pub use disjoint::{get_many_mut, get_many_mut_or_panic, DisjointAccessors, OverlapError};
+pub use dynamic::{AnyAccessor, DynAccessor};
+pub use interior::{ExclusiveLock, MappedGuard};
+pub use kind::ParseKindError;
+pub use lens::Lens;
+pub use optional::Optional;
+pub use pin::PinAccessor;
+pub use prism::Prism;
+#[doc(hidden)]
+pub use reflect::__private;
+pub use reflect::{FieldInfo, FieldTable, PathError};
+pub use tail::TailAccessor;
+pub use traversal::Traversal;
+pub use unaligned::UnalignedAccessor;
+pub use uninit::{InitField, InitFields, UninitBuilder};
+
 /// A small, copyable accessor that focuses into a field F inside a root T.
 ///
 /// Representation: a byte offset from the start of T to the field F. This
 /// allows cheap composition by offset addition. All operations are implemented
 /// via unsafe pointer arithmetic but expose a safe API.
-#[derive(Debug, Clone, Copy)]
-pub struct Accessor<T, F> {
+pub struct Accessor<T: ?Sized, F> {
     /// Byte offset from a T pointer to its field F.
     offset: isize,
     _phantom: PhantomData<fn(T) -> F>,
 }
 
-impl<T, F> Accessor<T, F> {
+// Manual impls: deriving would add `T: Clone`/`F: Clone` bounds, but an accessor is
+// just an offset and is `Copy` regardless of the types it focuses.
+impl<T: ?Sized, F> Clone for Accessor<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, F> Copy for Accessor<T, F> {}
+
+impl<T: ?Sized, F> core::fmt::Debug for Accessor<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Accessor")
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+// Equality and ordering compare offsets only; the types are part of the accessor's
+// type, so equal accessors focus the same field.
+impl<T: ?Sized, F> PartialEq for Accessor<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<T: ?Sized, F> Eq for Accessor<T, F> {}
+
+impl<T: ?Sized, F> core::hash::Hash for Accessor<T, F> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+    }
+}
+
+impl<T: ?Sized, F> PartialOrd for Accessor<T, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Accessors are ordered by position within the root.
+impl<T: ?Sized, F> Ord for Accessor<T, F> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.offset.cmp(&other.offset)
+    }
+}
+
+impl<T: ?Sized, F> Accessor<T, F> {
     /// Construct from a precomputed byte offset.
     ///
     /// # Safety
@@ -70,9 +194,13 @@ impl<T, F> Accessor<T, F> {
     ///   properly aligned for `F` and points to initialized memory owned by the same `T` object.
     /// - The accessor will only ever be used with values of type `T` that have the same layout
     ///   with respect to the field `F` (e.g., not a different type or transmuted layout).
+    /// - The path from `T` to `F` steps only through struct and tuple fields, never through a
+    ///   union field. Relations such as [`strip_prefix`](Self::strip_prefix) rely on fields
+    ///   not sharing bytes with their siblings.
     ///
     /// Violating any of these preconditions is undefined behavior. Prefer constructing accessors
-    /// via `#[derive(Accessor)]` or `Accessor::from_fns`, which compute valid offsets for you.
+    /// via `#[derive(Accessor)]` or [`field!`], which compute valid offsets for you, or use
+    /// [`from_offset_checked`](Self::from_offset_checked) to catch out-of-bounds offsets.
     pub const unsafe fn from_offset(offset: isize) -> Self {
         Self {
             offset,
@@ -80,9 +208,16 @@ impl<T, F> Accessor<T, F> {
         }
     }
 
-    /// Runtime constructor from field-selection functions. Computes the offset
-    /// using raw pointer projection without dereferencing invalid memory.
-    pub fn from_fns(get_ref: fn(&T) -> &F, _get_mut: fn(&mut T) -> &mut F) -> Self {
+    /// Runtime constructor from field-selection functions.
+    ///
+    /// This calls `get_ref` on a null pointer to find the offset, which is undefined
+    /// behaviour, and gives wrong results for any getter that does more than project a
+    /// field. Use [`field!`] instead, which is sound and `const`.
+    #[deprecated(note = "unsound; use `pathmod_core::field!(Type, a.b)` instead")]
+    pub fn from_fns(get_ref: fn(&T) -> &F, _get_mut: fn(&mut T) -> &mut F) -> Self
+    where
+        T: Sized,
+    {
         // Create an arbitrary base pointer; using null is fine since we don't deref.
         let base = core::ptr::null::<T>();
         // Obtain the address of the projected field via the provided getter by
@@ -100,6 +235,11 @@ impl<T, F> Accessor<T, F> {
         unsafe { Accessor::from_offset(offset) }
     }
 
+    /// The byte offset from the start of `T` to the focused field `F`.
+    pub const fn offset(&self) -> isize {
+        self.offset
+    }
+
     /// Borrow the focused field immutably.
     pub fn get<'a>(&self, root: &'a T) -> &'a F {
         unsafe {
@@ -147,7 +287,10 @@ impl<T, F> Accessor<T, F> {
     ///
     /// Given `self: Accessor<T, U>` and `next: Accessor<U, V>`, returns
     /// `Accessor<T, V>` that focuses by first going through `self` then `next`.
-    pub fn compose<V>(self, next: Accessor<F, V>) -> Accessor<T, V> {
+    ///
+    /// This is a `const fn`, so composed paths can be stored in `const` and `static`
+    /// items and built once at compile time.
+    pub const fn compose<V>(self, next: Accessor<F, V>) -> Accessor<T, V> {
         // Offsets add: T -> F, then F -> V.
         let offset = self.offset + next.offset;
         unsafe { Accessor::from_offset(offset) }
@@ -161,16 +304,11 @@ pub trait Indexing<T, E> {
     /// Borrow the element at `idx` immutably.
     ///
     /// ```rust
-    /// use pathmod_core::{Accessor, Indexing};
+    /// use pathmod_core::{field, Indexing};
     /// #[derive(Debug)]
     /// struct Bag { items: Vec<i32> }
-    /// fn acc_items() -> Accessor<Bag, Vec<i32>> {
-    ///     fn gr(b: &Bag) -> &Vec<i32> { &b.items }
-    ///     fn gm(b: &mut Bag) -> &mut Vec<i32> { &mut b.items }
-    ///     Accessor::from_fns(gr, gm)
-    /// }
     /// let b = Bag { items: vec![1,2,3] };
-    /// let acc = acc_items();
+    /// let acc = field!(Bag, items);
     /// assert_eq!(*acc.get_at(&b, 1), 2);
     /// ```
     fn get_at<'a>(&self, root: &'a T, idx: usize) -> &'a E;
@@ -213,5 +351,20 @@ impl<T, E> Indexing<T, E> for Accessor<T, Vec<E>> {
 
 pub mod prelude {
     pub use crate::Accessor;
+    pub use crate::AnyAccessor;
+    pub use crate::DerefHop;
+    pub use crate::DerefHopMut;
+    pub use crate::DerefPath;
+    pub use crate::DisjointAccessors;
+    pub use crate::DynAccessor;
+    pub use crate::FieldTable;
     pub use crate::Indexing;
+    pub use crate::Lens;
+    pub use crate::Optional;
+    pub use crate::PinAccessor;
+    pub use crate::Prism;
+    pub use crate::TailAccessor;
+    pub use crate::Traversal;
+    pub use crate::UnalignedAccessor;
+    pub use crate::UninitBuilder;
 }
@@ -0,0 +1,74 @@
+/// Build an [`Accessor`](crate::Accessor) for a nested field path in one step.
+///
+/// `path!(Root => a.b.c)` expands to a single `offset_of!` over the whole path, so
+/// the result is one offset and can be used in `const` items. Tuple indices work
+/// as in ordinary field access (`path!(Pair => 1.0)`). The field type is inferred
+/// from the path, and a misspelled segment is reported at that segment.
+///
+/// ```rust
+/// use pathmod_core::{path, Accessor};
+///
+/// struct Address { city: String }
+/// struct Profile { address: Address }
+/// struct User { id: u32, profile: Profile }
+/// struct Pair(u8, (i16, i64));
+///
+/// const CITY: Accessor<User, String> = path!(User => profile.address.city);
+/// let u = User { id: 1, profile: Profile { address: Address { city: "Lund".into() } } };
+/// assert_eq!(CITY.get(&u), "Lund");
+///
+/// let deep = path!(Pair => 1.1);
+/// assert_eq!(*deep.get(&Pair(0, (1, 2))), 2i64);
+/// ```
+///
+/// ```compile_fail
+/// use pathmod_core::path;
+/// struct User { name: String }
+/// let _ = path!(User => nmae);
+/// ```
+///
+/// Paths through union fields are rejected, since reading one needs `unsafe`:
+///
+/// ```compile_fail,E0133
+/// use pathmod_core::path;
+/// union Bits { a: u8, b: bool }
+/// let _ = path!(Bits => b);
+/// ```
+#[macro_export]
+macro_rules! path {
+    ($root:ty => $($field:tt)+) => {{
+        // Built outside the `unsafe` block, so a path through a union field, whose read
+        // needs `unsafe`, is rejected instead of silently allowed.
+        let project: fn(&$root) -> &_ = |root: &$root| &root.$($field)+;
+        let offset = ::core::mem::offset_of!($root, $($field)+);
+        // SAFETY: the offset is computed by `offset_of!` over this exact path of `$root`,
+        // and `project` pins the field type to the one found there.
+        unsafe { $crate::__private::typed_offset::<$root, _>(offset, project) }
+    }};
+}
+
+/// Build an [`Accessor`](crate::Accessor) to a field of a type you do not own.
+///
+/// `field!(Type, a.b.0)` is the hand-written counterpart of `#[derive(Accessor)]`:
+/// like [`path!`](crate::path) it expands to one `offset_of!` over the whole path, so
+/// it is sound for foreign types, needs no `unsafe`, and works in `const` items.
+/// Union fields are rejected, since naming one needs `unsafe`.
+///
+/// ```rust
+/// use pathmod_core::{field, Accessor};
+///
+/// // Pretend these come from another crate.
+/// struct Inner { x: i32 }
+/// struct Outer { tag: u8, inner: (u8, Inner) }
+///
+/// const X: Accessor<Outer, i32> = field!(Outer, inner.1.x);
+/// let mut o = Outer { tag: 0, inner: (1, Inner { x: 2 }) };
+/// X.set_mut(&mut o, |x| *x *= 21);
+/// assert_eq!(o.inner.1.x, 42);
+/// ```
+#[macro_export]
+macro_rules! field {
+    ($root:ty, $($field:tt)+) => {
+        $crate::path!($root => $($field)+)
+    };
+}
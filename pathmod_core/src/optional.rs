@@ -0,0 +1,374 @@
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::{Accessor, Prism};
+
+// Steps are stored type-erased over raw pointers so that composing optionals does
+// not tie the closure types to `T`/`F` (which would force `'static` bounds on them).
+// A null pointer means "absent". The typed surface below is the only way to build
+// these closures, which keeps the casts in sync with the phantom types.
+pub(crate) type RawGet = dyn Fn(*const ()) -> *const () + Send + Sync;
+pub(crate) type RawGetMut = dyn Fn(*mut ()) -> *mut () + Send + Sync;
+
+/// An optional (affine) focus on a value `F` inside `T` that may be absent.
+///
+/// Optionals are what you get when a path crosses a partial step, such as an
+/// enum variant ([`Prism`]). Reads return `Option`, and writes report whether
+/// they reached the focused value.
+///
+/// Unlike [`Accessor`], an `Optional` cannot be reduced to a byte offset: each
+/// step is kept as a shared closure, so cloning one is cheap but it is not `Copy`.
+pub struct Optional<T, F> {
+    get: Arc<RawGet>,
+    get_mut: Arc<RawGetMut>,
+    /// Like `get_mut`, but steps built with `some_or_default` materialize `Some` first.
+    get_or_insert: Arc<RawGetMut>,
+    _phantom: PhantomData<fn(T) -> F>,
+}
+
+impl<T, F> Clone for Optional<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            get: Arc::clone(&self.get),
+            get_mut: Arc::clone(&self.get_mut),
+            get_or_insert: Arc::clone(&self.get_or_insert),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> core::fmt::Debug for Optional<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Optional").finish_non_exhaustive()
+    }
+}
+
+impl<T, F> Optional<T, F> {
+    /// Construct an optional focus from a pair of projection functions.
+    ///
+    /// ```rust
+    /// use pathmod_core::Optional;
+    ///
+    /// struct Slot { value: Option<u32> }
+    ///
+    /// let value: Optional<Slot, u32> = Optional::new(
+    ///     |s: &Slot| s.value.as_ref(),
+    ///     |s: &mut Slot| s.value.as_mut(),
+    /// );
+    /// let mut s = Slot { value: None };
+    /// assert!(!value.set(&mut s, 1));
+    /// s.value = Some(0);
+    /// assert!(value.set(&mut s, 1));
+    /// assert_eq!(value.get(&s), Some(&1));
+    /// ```
+    pub fn new(
+        get: impl Fn(&T) -> Option<&F> + Send + Sync + 'static,
+        get_mut: impl Fn(&mut T) -> Option<&mut F> + Send + Sync + 'static,
+    ) -> Self {
+        let get_mut: Arc<RawGetMut> = Arc::new(move |root: *mut ()| {
+            // SAFETY: as in `get`, from a `&mut T` with the same exclusive lifetime.
+            match get_mut(unsafe { &mut *(root as *mut T) }) {
+                Some(f) => f as *mut F as *mut (),
+                None => core::ptr::null_mut(),
+            }
+        });
+        Self {
+            get: Arc::new(move |root: *const ()| {
+                // SAFETY: erased pointers are only ever produced from a `&T` in `Optional::get`
+                // or by a previous step whose output type is `T`.
+                match get(unsafe { &*(root as *const T) }) {
+                    Some(f) => f as *const F as *const (),
+                    None => core::ptr::null(),
+                }
+            }),
+            get_or_insert: Arc::clone(&get_mut),
+            get_mut,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Build from erased projection closures that never materialize missing values.
+    pub(crate) fn from_raw(get: Arc<RawGet>, get_mut: Arc<RawGetMut>) -> Self {
+        Optional {
+            get,
+            get_or_insert: Arc::clone(&get_mut),
+            get_mut,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Split into the erased projection closures, for building other optics.
+    pub(crate) fn into_raw(self) -> (Arc<RawGet>, Arc<RawGetMut>) {
+        (self.get, self.get_mut)
+    }
+
+    /// Borrow the focused value if it is present.
+    pub fn get<'a>(&self, root: &'a T) -> Option<&'a F> {
+        let ptr = (self.get)(root as *const T as *const ()) as *const F;
+        // SAFETY: a non-null result points into `root` (or memory it owns) and has type `F`.
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Borrow the focused value mutably if it is present.
+    pub fn get_mut<'a>(&self, root: &'a mut T) -> Option<&'a mut F> {
+        let ptr = (self.get_mut)(root as *mut T as *mut ()) as *mut F;
+        // SAFETY: as in `get`, derived from the exclusive borrow of `root`.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Borrow the focused value mutably, first materializing any `Option` steps built
+    /// with [`Accessor::some_or_default`] or [`Optional::some_or_default`] as
+    /// `Some(Default::default())`.
+    ///
+    /// Returns `None` only if a step that cannot be materialized (such as a prism or
+    /// a plain [`some`](Accessor::some)) is absent.
+    pub fn get_or_insert<'a>(&self, root: &'a mut T) -> Option<&'a mut F> {
+        let ptr = (self.get_or_insert)(root as *mut T as *mut ()) as *mut F;
+        // SAFETY: as in `get_mut`.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Whether the focused value is present in `root`.
+    pub fn is_present(&self, root: &T) -> bool {
+        self.get(root).is_some()
+    }
+
+    /// Set by moving a new value into the focused location, if present.
+    ///
+    /// Returns `true` if the value was written; when the focus is absent `root`
+    /// is left untouched and `value` is dropped.
+    pub fn set(&self, root: &mut T, value: F) -> bool {
+        match self.get_mut(root) {
+            Some(slot) => {
+                *slot = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Mutate the focused location in-place, if present.
+    ///
+    /// Returns `true` if the closure was called.
+    pub fn set_mut(&self, root: &mut T, f: impl FnOnce(&mut F)) -> bool {
+        match self.get_mut(root) {
+            Some(slot) => {
+                f(slot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Set by cloning the provided value into the focused location, if present.
+    ///
+    /// Only `F: Clone` is required, and the clone happens only when the focus is present.
+    pub fn set_clone(&self, root: &mut T, value: &F) -> bool
+    where
+        F: Clone,
+    {
+        match self.get_mut(root) {
+            Some(slot) => {
+                *slot = value.clone();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Compose this optional with a further focus, yielding an optional from `T` to `V`.
+    ///
+    /// `next` may be an [`Accessor`], a [`Prism`] or another [`Optional`].
+    pub fn compose<V>(self, next: impl Into<Optional<F, V>>) -> Optional<T, V> {
+        let next = next.into();
+        let (get, get_mut, get_or_insert) = (self.get, self.get_mut, self.get_or_insert);
+        let (next_get, next_get_mut, next_get_or_insert) =
+            (next.get, next.get_mut, next.get_or_insert);
+        Optional {
+            get: Arc::new(move |root| {
+                let f = get(root);
+                if f.is_null() {
+                    f
+                } else {
+                    next_get(f)
+                }
+            }),
+            get_mut: Arc::new(move |root| {
+                let f = get_mut(root);
+                if f.is_null() {
+                    f
+                } else {
+                    next_get_mut(f)
+                }
+            }),
+            get_or_insert: Arc::new(move |root| {
+                let f = get_or_insert(root);
+                if f.is_null() {
+                    f
+                } else {
+                    next_get_or_insert(f)
+                }
+            }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<F> Optional<Option<F>, F> {
+    /// The step from an `Option<F>` to its `Some` payload.
+    pub(crate) fn unwrap_some() -> Self {
+        let get_mut: Arc<RawGetMut> = Arc::new(|root| {
+            // SAFETY: the root pointer of this step always points to an `Option<F>`.
+            match unsafe { &mut *(root as *mut Option<F>) } {
+                Some(f) => f as *mut F as *mut (),
+                None => core::ptr::null_mut(),
+            }
+        });
+        Optional {
+            get: Arc::new(|root| {
+                // SAFETY: as above.
+                match unsafe { &*(root as *const Option<F>) } {
+                    Some(f) => f as *const F as *const (),
+                    None => core::ptr::null(),
+                }
+            }),
+            get_or_insert: Arc::clone(&get_mut),
+            get_mut,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Like `unwrap_some`, but `get_or_insert` materializes `Some(F::default())`.
+    fn unwrap_some_or_default() -> Self
+    where
+        F: Default,
+    {
+        Optional {
+            get_or_insert: Arc::new(|root| {
+                // SAFETY: the root pointer of this step always points to an `Option<F>`.
+                let opt = unsafe { &mut *(root as *mut Option<F>) };
+                opt.get_or_insert_with(F::default) as *mut F as *mut ()
+            }),
+            ..Self::unwrap_some()
+        }
+    }
+}
+
+impl<T, F> Optional<T, Option<F>> {
+    /// Continue into the `Some` payload of the focused `Option`.
+    pub fn some(self) -> Optional<T, F> {
+        self.compose(Optional::<Option<F>, F>::unwrap_some())
+    }
+
+    /// Like [`some`](Self::some), but [`get_or_insert`](Optional::get_or_insert)
+    /// materializes `Some(F::default())` when the focused `Option` is `None`.
+    pub fn some_or_default(self) -> Optional<T, F>
+    where
+        F: Default,
+    {
+        self.compose(Optional::<Option<F>, F>::unwrap_some_or_default())
+    }
+
+    /// Borrow the payload of the focused `Option` mutably, inserting `f()` if it is
+    /// `None`.
+    ///
+    /// Steps on the way are materialized as in [`get_or_insert`](Optional::get_or_insert);
+    /// returns `None` only if one of them cannot be.
+    pub fn get_or_insert_with<'a>(
+        &self,
+        root: &'a mut T,
+        f: impl FnOnce() -> F,
+    ) -> Option<&'a mut F> {
+        self.get_or_insert(root)
+            .map(|opt| opt.get_or_insert_with(f))
+    }
+}
+
+impl<T, F> Accessor<T, Option<F>> {
+    /// Focus the `Some` payload of the `Option` at this accessor.
+    ///
+    /// Reads return `None` and writes are no-ops while the field is `None`.
+    pub fn some(self) -> Optional<T, F> {
+        Optional::from(self).some()
+    }
+
+    /// Like [`some`](Self::some), but [`Optional::get_or_insert`] materializes
+    /// `Some(F::default())` when the field is `None`.
+    pub fn some_or_default(self) -> Optional<T, F>
+    where
+        F: Default,
+    {
+        Optional::from(self).some_or_default()
+    }
+
+    /// Borrow the payload of the focused `Option` mutably, inserting `f()` if it is `None`.
+    pub fn get_or_insert_with<'a>(&self, root: &'a mut T, f: impl FnOnce() -> F) -> &'a mut F {
+        self.get_mut(root).get_or_insert_with(f)
+    }
+}
+
+impl<T, F> Accessor<T, F> {
+    /// Compose this accessor with an optional focus, yielding an optional from `T` to `V`.
+    ///
+    /// `next` may be an [`Optional`] or a [`Prism`].
+    pub fn compose_optional<V>(self, next: impl Into<Optional<F, V>>) -> Optional<T, V> {
+        Optional::from(self).compose(next)
+    }
+}
+
+impl<T, F> From<Accessor<T, F>> for Optional<T, F> {
+    /// Every accessor is an optional focus that is always present.
+    fn from(acc: Accessor<T, F>) -> Self {
+        let offset = acc.offset();
+        Optional {
+            // SAFETY (both closures): `offset` comes from a valid accessor from `T` to `F`.
+            get: Arc::new(move |root: *const ()| unsafe { root.byte_offset(offset) }),
+            get_mut: Arc::new(move |root: *mut ()| unsafe { root.byte_offset(offset) }),
+            get_or_insert: Arc::new(move |root: *mut ()| unsafe { root.byte_offset(offset) }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> From<Prism<T, F>> for Optional<T, F> {
+    fn from(prism: Prism<T, F>) -> Self {
+        // The prism's function pointers mention `T` and `F`; capturing them as-is would
+        // make the closures `'static` only for `'static` types. Erase them to `fn()` and
+        // call back through monomorphized trampolines, whose pointer types are plain.
+        unsafe fn preview<T, F>(f: fn(), root: *const ()) -> *const () {
+            // SAFETY: `f` was erased from exactly this type below.
+            let f = unsafe { core::mem::transmute::<fn(), fn(&T) -> Option<&F>>(f) };
+            // SAFETY: see `Optional::new`.
+            match f(unsafe { &*(root as *const T) }) {
+                Some(v) => v as *const F as *const (),
+                None => core::ptr::null(),
+            }
+        }
+        unsafe fn preview_mut<T, F>(f: fn(), root: *mut ()) -> *mut () {
+            // SAFETY: `f` was erased from exactly this type below.
+            let f = unsafe { core::mem::transmute::<fn(), fn(&mut T) -> Option<&mut F>>(f) };
+            // SAFETY: see `Optional::new`.
+            match f(unsafe { &mut *(root as *mut T) }) {
+                Some(v) => v as *mut F as *mut (),
+                None => core::ptr::null_mut(),
+            }
+        }
+
+        // SAFETY: function pointers have the same layout; they are only called back
+        // through the matching trampoline.
+        let get_fn = unsafe { core::mem::transmute::<fn(&T) -> Option<&F>, fn()>(prism.preview) };
+        let get_mut_fn = unsafe {
+            core::mem::transmute::<fn(&mut T) -> Option<&mut F>, fn()>(prism.preview_mut)
+        };
+        let get_tramp: unsafe fn(fn(), *const ()) -> *const () = preview::<T, F>;
+        let get_mut_tramp: unsafe fn(fn(), *mut ()) -> *mut () = preview_mut::<T, F>;
+        let get_mut: Arc<RawGetMut> =
+            Arc::new(move |root| unsafe { get_mut_tramp(get_mut_fn, root) });
+        Optional {
+            get: Arc::new(move |root| unsafe { get_tramp(get_fn, root) }),
+            get_or_insert: Arc::clone(&get_mut),
+            get_mut,
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use core::pin::Pin;
+
+use crate::Accessor;
+
+/// An accessor to a structurally pinned field: projects `Pin<&mut T>` to
+/// `Pin<&mut F>`.
+///
+/// A plain [`Accessor`] can only reach a field of a pinned value through
+/// `Pin::get_unchecked_mut`, which is unsafe. `PinAccessor` packages the promise
+/// that the field is *structurally pinned*, so projecting through it is safe.
+/// `#[derive(Accessor)]` generates `pin_<field>()` for fields marked
+/// `#[pathmod(pin)]`, and checks the rules below at compile time.
+///
+/// ```rust
+/// use core::pin::pin;
+/// use pathmod_core::{field, PinAccessor};
+///
+/// struct Task { fut: core::future::Ready<u32>, polls: u32 }
+///
+/// // SAFETY: `Task` does not implement `Drop` or `Unpin` by hand, is not packed, and
+/// // never moves out of `fut`.
+/// let fut = unsafe { PinAccessor::new(field!(Task, fut)) };
+///
+/// let mut task = pin!(Task { fut: core::future::ready(7), polls: 0 });
+/// let _: core::pin::Pin<&mut core::future::Ready<u32>> = fut.project_pin(task.as_mut());
+/// ```
+pub struct PinAccessor<T, F> {
+    acc: Accessor<T, F>,
+}
+
+impl<T, F> Clone for PinAccessor<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for PinAccessor<T, F> {}
+
+impl<T, F> core::fmt::Debug for PinAccessor<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PinAccessor")
+            .field("offset", &self.acc.offset())
+            .finish()
+    }
+}
+
+impl<T, F> PinAccessor<T, F> {
+    /// Mark the field focused by `acc` as structurally pinned.
+    ///
+    /// # Safety
+    /// Once a `T` is pinned, the focused field must never be moved or invalidated
+    /// without being dropped in place. In particular:
+    /// - `T` must only implement `Unpin` if `F` does;
+    /// - a `Drop` impl of `T` (or of anything on the path) must not move out of the
+    ///   field;
+    /// - `T` must not be `#[repr(packed)]`;
+    /// - no other API of `T` may move out of the field while it is pinned.
+    pub const unsafe fn new(acc: Accessor<T, F>) -> Self {
+        Self { acc }
+    }
+
+    /// The underlying accessor, for unpinned access through `&T` or `&mut T`.
+    pub const fn accessor(self) -> Accessor<T, F> {
+        self.acc
+    }
+
+    /// Borrow the focused field of a pinned reference as pinned.
+    pub fn get_pin<'a>(&self, root: Pin<&'a T>) -> Pin<&'a F> {
+        // SAFETY: the field is structurally pinned (see `new`).
+        unsafe { root.map_unchecked(|root| self.acc.get(root)) }
+    }
+
+    /// Project a pinned mutable reference to the focused field, keeping it pinned.
+    pub fn project_pin<'a>(&self, root: Pin<&'a mut T>) -> Pin<&'a mut F> {
+        // SAFETY: the field is structurally pinned (see `new`), so it is never moved
+        // out of through the returned reference.
+        unsafe { root.map_unchecked_mut(|root| self.acc.get_mut(root)) }
+    }
+
+    /// Replace the focused field of a pinned value, dropping the old value in place,
+    /// as [`Pin::set`].
+    pub fn set_pin(&self, root: Pin<&mut T>, value: F) {
+        self.project_pin(root).set(value);
+    }
+
+    /// Compose with a pin accessor into the focused field, yielding a pin accessor
+    /// from `T` to `V`.
+    pub const fn compose<V>(self, next: PinAccessor<F, V>) -> PinAccessor<T, V> {
+        PinAccessor {
+            acc: self.acc.compose(next.acc),
+        }
+    }
+}
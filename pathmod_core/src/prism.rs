@@ -0,0 +1,122 @@
+use crate::{Accessor, Optional};
+
+/// A partial focus on a value `F` that may or may not be present inside `T`.
+///
+/// The typical example is the payload of one enum variant: `preview` succeeds only
+/// while the root holds that variant, and `review` builds a root from a payload.
+/// Prisms are small, `Copy` values made of three function pointers and are usually
+/// generated by `#[derive(EnumAccess)]` as `prism_<variant>()`.
+///
+/// Composing a prism with an [`Accessor`] (in either direction) yields an
+/// [`Optional`], a focus that can be read and written only when it is present.
+pub struct Prism<T, F> {
+    pub(crate) preview: fn(&T) -> Option<&F>,
+    pub(crate) preview_mut: fn(&mut T) -> Option<&mut F>,
+    review: fn(F) -> T,
+}
+
+impl<T, F> Clone for Prism<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for Prism<T, F> {}
+
+impl<T, F> core::fmt::Debug for Prism<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Prism").finish_non_exhaustive()
+    }
+}
+
+impl<T, F> Prism<T, F> {
+    /// Construct a prism from its matching, mutable matching and building functions.
+    ///
+    /// ```rust
+    /// use pathmod_core::Prism;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Msg { Int(i32), Text(String) }
+    ///
+    /// let text: Prism<Msg, String> = Prism::new(
+    ///     |m| if let Msg::Text(s) = m { Some(s) } else { None },
+    ///     |m| if let Msg::Text(s) = m { Some(s) } else { None },
+    ///     Msg::Text,
+    /// );
+    ///
+    /// let mut m = Msg::Int(1);
+    /// assert!(text.preview(&m).is_none());
+    /// text.set(&mut m, "hi".to_string());
+    /// assert_eq!(text.preview(&m).map(String::as_str), Some("hi"));
+    /// ```
+    pub const fn new(
+        preview: fn(&T) -> Option<&F>,
+        preview_mut: fn(&mut T) -> Option<&mut F>,
+        review: fn(F) -> T,
+    ) -> Self {
+        Self {
+            preview,
+            preview_mut,
+            review,
+        }
+    }
+
+    /// Borrow the focused value if it is present.
+    pub fn preview<'a>(&self, root: &'a T) -> Option<&'a F> {
+        (self.preview)(root)
+    }
+
+    /// Borrow the focused value mutably if it is present.
+    pub fn preview_mut<'a>(&self, root: &'a mut T) -> Option<&'a mut F> {
+        (self.preview_mut)(root)
+    }
+
+    /// Build a new root from a focused value.
+    pub fn review(&self, value: F) -> T {
+        (self.review)(value)
+    }
+
+    /// Whether the focused value is present in `root`.
+    pub fn matches(&self, root: &T) -> bool {
+        self.preview(root).is_some()
+    }
+
+    /// Replace the whole root with one built from `value`.
+    ///
+    /// Unlike [`Optional::set`], this always succeeds: for an enum it switches the
+    /// root to the focused variant.
+    pub fn set(&self, root: &mut T, value: F) {
+        *root = self.review(value);
+    }
+
+    /// Mutate the focused value in-place if it is present.
+    ///
+    /// Returns `true` if the closure was called.
+    pub fn set_mut(&self, root: &mut T, f: impl FnOnce(&mut F)) -> bool {
+        match self.preview_mut(root) {
+            Some(v) => {
+                f(v);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Compose this prism with a further focus, yielding an optional focus from
+    /// `T` to `V`.
+    ///
+    /// `next` may be an [`Accessor`], another [`Prism`] or an [`Optional`].
+    pub fn compose<V>(self, next: impl Into<Optional<F, V>>) -> Optional<T, V> {
+        Optional::from(self).compose(next)
+    }
+}
+
+impl<T, F> Accessor<T, F> {
+    /// Compose this accessor with a prism, yielding an optional focus from `T` to `V`.
+    ///
+    /// Given `self: Accessor<T, F>` and `next: Prism<F, V>`, the result focuses `V`
+    /// whenever the value at `self` currently matches `next`.
+    pub fn compose_prism<V>(self, next: Prism<F, V>) -> Optional<T, V> {
+        Optional::from(self).compose(next)
+    }
+}
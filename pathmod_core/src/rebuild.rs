@@ -0,0 +1,100 @@
+use core::mem::ManuallyDrop;
+
+use crate::Accessor;
+
+impl<T, F> Accessor<T, F> {
+    /// Consume `root` and return it with the focused field replaced by `value`.
+    ///
+    /// This is the by-value counterpart of [`set`](Self::set) for immutable-style
+    /// code: for a composed path it rebuilds every level at once, moving all sibling
+    /// fields and cloning nothing, like chaining the derived `with_*` helpers by hand.
+    /// The previous value is dropped.
+    ///
+    /// ```rust
+    /// use pathmod_core::field;
+    ///
+    /// struct Address { city: String, zip: u32 }
+    /// struct User { name: String, address: Address }
+    ///
+    /// let u = User { name: "ann".into(), address: Address { city: "berlin".into(), zip: 10115 } };
+    /// let u = field!(User, address.city).replace(u, "Lund".into());
+    /// assert_eq!((u.name.as_str(), u.address.city.as_str(), u.address.zip), ("ann", "Lund", 10115));
+    /// ```
+    pub fn replace(&self, root: T, value: F) -> T {
+        // A composed accessor is one offset with no per-level types, so instead of
+        // chaining each level's `with_*` the field is overwritten in place. This moves
+        // only the focused field and also works for `field!` accessors on types
+        // without the derive.
+        let mut root = root;
+        self.set(&mut root, value);
+        root
+    }
+
+    /// Consume `root` and return it with the focused field passed through `f` by value.
+    ///
+    /// Unlike [`set_mut`](Self::set_mut), `f` owns the old value, so it can be
+    /// consumed or rebuilt without `F: Clone` or `F: Default`. If `f` panics, the rest
+    /// of `root` is leaked rather than dropped, since its field has been moved out.
+    ///
+    /// ```rust
+    /// use pathmod_core::field;
+    ///
+    /// struct Doc { title: String, tags: Vec<String> }
+    ///
+    /// let d = Doc { title: "t".into(), tags: vec!["a".into()] };
+    /// let d = field!(Doc, tags).modify(d, |tags| tags.into_iter().chain(["b".into()]).collect());
+    /// assert_eq!(d.tags, ["a", "b"]);
+    /// ```
+    pub fn modify(&self, root: T, f: impl FnOnce(F) -> F) -> T {
+        let mut root = ManuallyDrop::new(root);
+        let field = self.get_mut(&mut root) as *mut F;
+        // SAFETY: `field` points to an initialized `F` inside `root`. It is moved out,
+        // and a new value is written back before `root` is released from
+        // `ManuallyDrop`, so the old value is neither used again nor dropped twice. If
+        // `f` unwinds, `root` stays in `ManuallyDrop` and is never dropped.
+        unsafe {
+            let old = field.read();
+            field.write(f(old));
+        }
+        ManuallyDrop::into_inner(root)
+    }
+
+    /// Return an updated copy of `root` with the focused field set to `value`, leaving
+    /// `root` untouched.
+    ///
+    /// This is the persistent counterpart of [`set`](Self::set) for undo-friendly
+    /// snapshots. An accessor stays within one allocation, so the whole root is
+    /// cloned; only `T: Clone` is required. To share unchanged subtrees between
+    /// snapshots, keep them behind `Rc`/`Arc` and use
+    /// [`DerefPath::updated`](crate::DerefPath::updated), which clones only the spine.
+    ///
+    /// ```rust
+    /// use pathmod_core::field;
+    ///
+    /// #[derive(Clone)]
+    /// struct Address { city: String }
+    /// #[derive(Clone)]
+    /// struct User { address: Address }
+    ///
+    /// let state = User { address: Address { city: "berlin".into() } };
+    /// let next = field!(User, address.city).updated(&state, "Lund".into());
+    /// assert_eq!((state.address.city.as_str(), next.address.city.as_str()), ("berlin", "Lund"));
+    /// ```
+    pub fn updated(&self, root: &T, value: F) -> T
+    where
+        T: Clone,
+    {
+        self.replace(root.clone(), value)
+    }
+
+    /// Like [`updated`](Self::updated), but mutates the focused field of the copy in
+    /// place.
+    pub fn updated_with(&self, root: &T, f: impl FnOnce(&mut F)) -> T
+    where
+        T: Clone,
+    {
+        let mut next = root.clone();
+        f(self.get_mut(&mut next));
+        next
+    }
+}
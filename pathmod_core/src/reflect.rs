@@ -0,0 +1,271 @@
+use core::any::TypeId;
+use core::fmt;
+
+use crate::dynamic::ErasedType;
+use crate::DynAccessor;
+
+/// Static field metadata emitted by `#[derive(Accessor)]`.
+///
+/// `FIELDS` lists the fields of `Self` in declaration order. It is what
+/// [`DynAccessor::resolve`] walks to turn a string path such as
+/// `"profile.address.city"` into an accessor at runtime. The table is usable
+/// wherever `Self` is, so the derive leaves out fields less visible than the type.
+///
+/// # Safety
+/// Every entry must describe a real field of `Self`: its offset must come from
+/// `offset_of!(Self, field)` and its type must be the field's type. Nested tables
+/// must likewise belong to the field type. Listing a field that some code able to
+/// name `Self` cannot see hands that code the field anyway. Prefer
+/// `#[derive(Accessor)]`.
+pub unsafe trait FieldTable: 'static {
+    /// The fields of `Self`, in declaration order.
+    const FIELDS: &'static [FieldInfo];
+}
+
+/// Metadata for one field: its name, byte offset and type.
+///
+/// Construct with [`FieldInfo::new`]; the derive does this for you.
+#[derive(Clone, Copy)]
+pub struct FieldInfo {
+    name: &'static str,
+    offset: usize,
+    ty: ErasedType,
+    fields: fn() -> Option<&'static [FieldInfo]>,
+}
+
+impl FieldInfo {
+    /// Describe a field of type `F` named `name` at byte `offset` within its parent.
+    ///
+    /// `fields` returns the field table of `F` itself, if it has one, so that paths
+    /// can continue into it. Tuple fields are named by their index (`"0"`, `"1"`, ...).
+    pub const fn new<F: 'static>(
+        name: &'static str,
+        offset: usize,
+        fields: fn() -> Option<&'static [FieldInfo]>,
+    ) -> Self {
+        Self {
+            name,
+            offset,
+            ty: ErasedType::of::<F>(),
+            fields,
+        }
+    }
+
+    /// Field name, or its index for tuple fields.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Byte offset of the field within its parent.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// `TypeId` of the field type.
+    pub fn type_id(&self) -> TypeId {
+        self.ty.type_id()
+    }
+
+    /// Name of the field type, as given by `core::any::type_name`.
+    pub fn type_name(&self) -> &'static str {
+        self.ty.type_name()
+    }
+
+    /// The field table of the field type, if it derives `Accessor`.
+    pub fn fields(&self) -> Option<&'static [FieldInfo]> {
+        (self.fields)()
+    }
+}
+
+impl fmt::Debug for FieldInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldInfo")
+            .field("name", &self.name)
+            .field("offset", &self.offset)
+            .field("type_name", &self.type_name())
+            .finish()
+    }
+}
+
+/// Error returned when a runtime path cannot be resolved or downcast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    /// The path string was empty or contained an empty segment (e.g. `"a..b"`).
+    EmptySegment {
+        /// The full path that was parsed.
+        path: String,
+    },
+    /// A segment does not name a field of the type reached so far.
+    UnknownField {
+        /// The offending segment.
+        segment: String,
+        /// The type that was searched.
+        type_name: &'static str,
+        /// The fields that type does have.
+        available: Vec<&'static str>,
+    },
+    /// A segment tried to continue into a type without a field table.
+    NotAStruct {
+        /// The offending segment.
+        segment: String,
+        /// The type that has no field table.
+        type_name: &'static str,
+    },
+    /// The resolved field has a different type than requested.
+    TypeMismatch {
+        /// The requested field type.
+        expected: &'static str,
+        /// The actual field type.
+        found: &'static str,
+    },
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::EmptySegment { path } => write!(f, "empty segment in path `{path}`"),
+            PathError::UnknownField {
+                segment,
+                type_name,
+                available,
+            } => write!(
+                f,
+                "no field `{segment}` on `{type_name}` (available: {})",
+                available.join(", ")
+            ),
+            PathError::NotAStruct { segment, type_name } => write!(
+                f,
+                "cannot access `{segment}`: `{type_name}` has no field table (does it derive Accessor?)"
+            ),
+            PathError::TypeMismatch { expected, found } => {
+                write!(f, "field has type `{found}`, not `{expected}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+impl<T: FieldTable> DynAccessor<T> {
+    /// Resolve a dot-separated field path (e.g. `"profile.address.city"` or `"0.1"`)
+    /// against the derived field tables of `T`.
+    ///
+    /// ```rust
+    /// use pathmod_core::{Accessor, DynAccessor, FieldInfo, FieldTable};
+    ///
+    /// struct Point { x: i32, y: i32 }
+    /// // SAFETY: offsets and types match the fields of `Point`.
+    /// unsafe impl FieldTable for Point {
+    ///     const FIELDS: &'static [FieldInfo] = &[
+    ///         FieldInfo::new::<i32>("x", core::mem::offset_of!(Point, x), || None),
+    ///         FieldInfo::new::<i32>("y", core::mem::offset_of!(Point, y), || None),
+    ///     ];
+    /// }
+    ///
+    /// let y: Accessor<Point, i32> = DynAccessor::<Point>::resolve("y")?.try_downcast()?;
+    /// assert_eq!(*y.get(&Point { x: 1, y: 2 }), 2);
+    /// assert!(DynAccessor::<Point>::resolve("z").is_err());
+    /// # Ok::<(), pathmod_core::PathError>(())
+    /// ```
+    pub fn resolve(path: &str) -> Result<Self, PathError> {
+        let mut table: Option<&'static [FieldInfo]> = Some(T::FIELDS);
+        let mut type_name = core::any::type_name::<T>();
+        let mut found: Option<&'static FieldInfo> = None;
+        let mut offset = 0usize;
+
+        for segment in path.split('.') {
+            if segment.is_empty() {
+                return Err(PathError::EmptySegment { path: path.into() });
+            }
+            let fields = table.ok_or_else(|| PathError::NotAStruct {
+                segment: segment.into(),
+                type_name,
+            })?;
+            let field = fields.iter().find(|f| f.name == segment).ok_or_else(|| {
+                PathError::UnknownField {
+                    segment: segment.into(),
+                    type_name,
+                    available: fields.iter().map(|f| f.name).collect(),
+                }
+            })?;
+            offset += field.offset;
+            table = field.fields();
+            type_name = field.type_name();
+            found = Some(field);
+        }
+
+        // `split` always yields at least one segment, so `found` is set here.
+        let field = found.expect("path has at least one segment");
+        // SAFETY: `offset` is the sum of derived field offsets along a valid path from
+        // `T`, and `field` describes the type found there.
+        Ok(unsafe { DynAccessor::from_raw_parts(offset as isize, field.ty) })
+    }
+}
+
+/// Support items for code generated by `pathmod_derive`. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    use super::{FieldInfo, FieldTable};
+    use crate::Accessor;
+    use core::marker::PhantomData;
+
+    /// Build an accessor from an `offset_of!` result; `_project` only fixes `F`.
+    ///
+    /// # Safety
+    /// `offset` must be the offset of the field that `_project` borrows.
+    pub const unsafe fn typed_offset<T, F>(
+        offset: usize,
+        _project: fn(&T) -> &F,
+    ) -> Accessor<T, F> {
+        // SAFETY: guaranteed by the caller.
+        unsafe { Accessor::from_offset(offset as isize) }
+    }
+
+    /// A `&mut ()` for the payload of unit variants.
+    pub fn unit_mut<'a>() -> &'a mut () {
+        // SAFETY: `()` is zero-sized, so any non-null aligned pointer is a valid,
+        // unaliased `&mut ()`.
+        unsafe { &mut *core::ptr::NonNull::<()>::dangling().as_ptr() }
+    }
+
+    /// Implements `Unpin` exactly when `T` does. Derived `Unpin` impls for
+    /// `#[pathmod(pin)]` structs bound on it, as `Wrapper<'_, (pinned fields,)>: Unpin`,
+    /// so that the bound mentions an impl parameter even when the field types do not.
+    pub struct Wrapper<'a, T>(PhantomData<&'a ()>, T);
+
+    /// Autoref-based probe: `(&&TableProbe::<F>::new()).field_table()` returns the
+    /// field table of `F` if it implements [`FieldTable`], and `None` otherwise.
+    pub struct TableProbe<F>(PhantomData<F>);
+
+    impl<F> TableProbe<F> {
+        pub const fn new() -> Self {
+            TableProbe(PhantomData)
+        }
+    }
+
+    impl<F> Default for TableProbe<F> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub trait ViaFieldTable {
+        fn field_table(&self) -> Option<&'static [FieldInfo]>;
+    }
+
+    impl<F: FieldTable> ViaFieldTable for &TableProbe<F> {
+        fn field_table(&self) -> Option<&'static [FieldInfo]> {
+            Some(F::FIELDS)
+        }
+    }
+
+    pub trait ViaNoFieldTable {
+        fn field_table(&self) -> Option<&'static [FieldInfo]>;
+    }
+
+    impl<F> ViaNoFieldTable for TableProbe<F> {
+        fn field_table(&self) -> Option<&'static [FieldInfo]> {
+            None
+        }
+    }
+}
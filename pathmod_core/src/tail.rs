@@ -0,0 +1,87 @@
+/// An accessor to the dynamically sized last field of a struct, such as the `data`
+/// of `struct Packet { len: u16, data: [u8] }` or a `str` or `dyn Trait` tail.
+///
+/// An [`Accessor`](crate::Accessor) is a fixed byte offset, but the offset of an
+/// unsized field is not always a compile-time constant (it depends on the alignment
+/// of a `dyn` tail), and a reference to it needs the slice length or vtable of the
+/// root. A `TailAccessor` therefore stores a place projection, which carries the root
+/// pointer's metadata over to the field. Sized fields of an unsized root still use
+/// plain accessors.
+///
+/// `#[derive(Accessor)]` generates these for a last field of type `[T]`, `str`,
+/// `dyn Trait` or a `?Sized` type parameter.
+///
+/// ```rust
+/// use pathmod_core::TailAccessor;
+///
+/// struct Packet<D: ?Sized> { len: u16, data: D }
+/// const DATA: TailAccessor<Packet<[u8]>, [u8]> = {
+///     let project: fn(*mut Packet<[u8]>) -> *mut [u8] = |p| unsafe { &raw mut (*p).data };
+///     // SAFETY: the projection only computes the address of the `data` field.
+///     unsafe { TailAccessor::from_projection(project) }
+/// };
+///
+/// let mut packet: Box<Packet<[u8]>> = Box::new(Packet { len: 3, data: [1, 2, 3] });
+/// DATA.get_mut(&mut packet).reverse();
+/// assert_eq!(DATA.get(&packet), &[3, 2, 1]);
+/// assert_eq!(packet.len, 3);
+/// ```
+pub struct TailAccessor<T: ?Sized, F: ?Sized> {
+    project: fn(*mut T) -> *mut F,
+}
+
+impl<T: ?Sized, F: ?Sized> Clone for TailAccessor<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, F: ?Sized> Copy for TailAccessor<T, F> {}
+
+impl<T: ?Sized, F: ?Sized> core::fmt::Debug for TailAccessor<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TailAccessor").finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized, F: ?Sized> TailAccessor<T, F> {
+    /// Construct from a projection that maps a pointer to the root to a pointer to
+    /// its field, typically `|p| unsafe { &raw mut (*p).field }`.
+    ///
+    /// # Safety
+    /// `project` must only compute the address of a field of `*p` (it is called with
+    /// pointers derived from live references, and must not read or write through them),
+    /// and the result must point into the same allocation with the field's metadata.
+    pub const unsafe fn from_projection(project: fn(*mut T) -> *mut F) -> Self {
+        Self { project }
+    }
+
+    /// Borrow the focused field immutably.
+    pub fn get<'a>(&self, root: &'a T) -> &'a F {
+        // SAFETY: the projection only computes a field address within `root`, and the
+        // resulting pointer is only read through.
+        unsafe { &*(self.project)(root as *const T as *mut T) }
+    }
+
+    /// Borrow the focused field mutably.
+    pub fn get_mut<'a>(&self, root: &'a mut T) -> &'a mut F {
+        // SAFETY: as in `get`, derived from the exclusive borrow of `root`.
+        unsafe { &mut *(self.project)(root) }
+    }
+
+    /// Mutate the focused location in-place using the provided closure.
+    pub fn set_mut(&self, root: &mut T, f: impl FnOnce(&mut F)) {
+        f(self.get_mut(root));
+    }
+
+    /// Set by moving a new value into the focused location.
+    ///
+    /// Only available when the field is sized, e.g. for a `?Sized` type parameter that
+    /// was instantiated with a sized type.
+    pub fn set(&self, root: &mut T, value: F)
+    where
+        F: Sized,
+    {
+        *self.get_mut(root) = value;
+    }
+}
@@ -0,0 +1,196 @@
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::{Accessor, Optional, Prism};
+
+// Like `Optional`, steps are type-erased over raw pointers so composition does not
+// require `'static` bounds on the focused types. Each step visits every focused
+// location by calling the sink with a pointer to it.
+type RawEach = dyn Fn(*const (), &mut dyn FnMut(*const ())) + Send + Sync;
+type RawEachMut = dyn Fn(*mut (), &mut dyn FnMut(*mut ())) + Send + Sync;
+
+/// A focus on zero or more values `E` inside `T`, such as every element of a `Vec`.
+///
+/// Traversals are created with [`Accessor::each`] on an accessor that focuses a
+/// `Vec<E>`, and compose further with accessors, prisms, optionals and other
+/// traversals. Elements are visited in order.
+///
+/// ```rust
+/// use pathmod_core::{Accessor, Traversal};
+///
+/// struct Item { price: u32 }
+/// struct Order { items: Vec<Item> }
+///
+/// let items: Accessor<Order, Vec<Item>> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Order, items) as isize) };
+/// let price: Accessor<Item, u32> =
+///     unsafe { Accessor::from_offset(core::mem::offset_of!(Item, price) as isize) };
+///
+/// let prices: Traversal<Order, u32> = items.each().compose(price);
+/// let mut o = Order { items: vec![Item { price: 100 }, Item { price: 50 }] };
+/// prices.map_in_place(&mut o, |p| p * 9 / 10);
+/// assert_eq!(prices.iter(&o).copied().collect::<Vec<_>>(), vec![90, 45]);
+/// ```
+pub struct Traversal<T, E> {
+    each: Arc<RawEach>,
+    each_mut: Arc<RawEachMut>,
+    _phantom: PhantomData<fn(T) -> E>,
+}
+
+impl<T, E> Clone for Traversal<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            each: Arc::clone(&self.each),
+            each_mut: Arc::clone(&self.each_mut),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, E> core::fmt::Debug for Traversal<T, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Traversal").finish_non_exhaustive()
+    }
+}
+
+impl<T, E> Traversal<T, E> {
+    /// Visit every focused value immutably, in order.
+    pub fn for_each<'a>(&self, root: &'a T, mut f: impl FnMut(&'a E))
+    where
+        E: 'a,
+    {
+        (self.each)(root as *const T as *const (), &mut |e| {
+            // SAFETY: every pointer handed to the sink points to an `E` owned by `root`.
+            f(unsafe { &*(e as *const E) })
+        });
+    }
+
+    /// Visit every focused value mutably, in order.
+    pub fn for_each_mut(&self, root: &mut T, mut f: impl FnMut(&mut E)) {
+        (self.each_mut)(root as *mut T as *mut (), &mut |e| {
+            // SAFETY: as in `for_each`; the visited locations are pairwise distinct and
+            // each borrow ends before the next one is created.
+            f(unsafe { &mut *(e as *mut E) })
+        });
+    }
+
+    /// Collect references to every focused value, in order.
+    pub fn iter<'a>(&self, root: &'a T) -> std::vec::IntoIter<&'a E> {
+        let mut out = Vec::new();
+        self.for_each(root, |e| out.push(e));
+        out.into_iter()
+    }
+
+    /// Replace every focused value with the result of `f` applied to it.
+    pub fn map_in_place(&self, root: &mut T, mut f: impl FnMut(&E) -> E) {
+        self.for_each_mut(root, |e| *e = f(e));
+    }
+
+    /// Number of values currently focused in `root`.
+    pub fn count(&self, root: &T) -> usize {
+        let mut n = 0;
+        self.for_each(root, |_| n += 1);
+        n
+    }
+
+    /// Compose this traversal with a further focus, yielding a traversal from `T` to `V`.
+    ///
+    /// `next` may be an [`Accessor`], a [`Prism`], an [`Optional`] or another
+    /// [`Traversal`]; partial steps simply skip elements where they do not match.
+    pub fn compose<V>(self, next: impl Into<Traversal<E, V>>) -> Traversal<T, V> {
+        let next = next.into();
+        let (each, each_mut) = (self.each, self.each_mut);
+        let (next_each, next_each_mut) = (next.each, next.each_mut);
+        Traversal {
+            each: Arc::new(move |root, sink| each(root, &mut |e| next_each(e, sink))),
+            each_mut: Arc::new(move |root, sink| each_mut(root, &mut |e| next_each_mut(e, sink))),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, E> Traversal<T, Vec<E>> {
+    /// Focus every element of every focused `Vec`.
+    pub fn each(self) -> Traversal<T, E> {
+        self.compose(Traversal::<Vec<E>, E>::elements())
+    }
+}
+
+impl<T, F> Traversal<T, Option<F>> {
+    /// Continue into the `Some` payload of every focused `Option`, skipping `None`s.
+    pub fn some(self) -> Traversal<T, F> {
+        self.compose(Optional::<Option<F>, F>::unwrap_some())
+    }
+}
+
+impl<E> Traversal<Vec<E>, E> {
+    /// The traversal over the elements of a `Vec` itself.
+    fn elements() -> Self {
+        Traversal {
+            each: Arc::new(|root, sink| {
+                // SAFETY: the root pointer of this step always points to a `Vec<E>`.
+                let v = unsafe { &*(root as *const Vec<E>) };
+                for e in v {
+                    sink(e as *const E as *const ());
+                }
+            }),
+            each_mut: Arc::new(|root, sink| {
+                // SAFETY: as above, with exclusive access.
+                let v = unsafe { &mut *(root as *mut Vec<E>) };
+                for e in v {
+                    sink(e as *mut E as *mut ());
+                }
+            }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, E> Accessor<T, Vec<E>> {
+    /// Focus every element of the `Vec` at this accessor.
+    ///
+    /// Unlike [`Indexing`](crate::Indexing), the resulting [`Traversal`] never panics
+    /// on length: it simply visits whatever elements are present.
+    pub fn each(self) -> Traversal<T, E> {
+        Traversal::from(self).each()
+    }
+}
+
+impl<T, F> From<Accessor<T, F>> for Traversal<T, F> {
+    fn from(acc: Accessor<T, F>) -> Self {
+        let offset = acc.offset();
+        Traversal {
+            // SAFETY (both closures): `offset` comes from a valid accessor from `T` to `F`.
+            each: Arc::new(move |root, sink| sink(unsafe { root.byte_offset(offset) })),
+            each_mut: Arc::new(move |root, sink| sink(unsafe { root.byte_offset(offset) })),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> From<Optional<T, F>> for Traversal<T, F> {
+    fn from(opt: Optional<T, F>) -> Self {
+        let (get, get_mut) = opt.into_raw();
+        Traversal {
+            each: Arc::new(move |root, sink| {
+                let f = get(root);
+                if !f.is_null() {
+                    sink(f);
+                }
+            }),
+            each_mut: Arc::new(move |root, sink| {
+                let f = get_mut(root);
+                if !f.is_null() {
+                    sink(f);
+                }
+            }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> From<Prism<T, F>> for Traversal<T, F> {
+    fn from(prism: Prism<T, F>) -> Self {
+        Optional::from(prism).into()
+    }
+}
@@ -0,0 +1,134 @@
+use core::marker::PhantomData;
+
+use crate::Accessor;
+
+/// An accessor to a field that may not be aligned for its type, such as a field of a
+/// `#[repr(packed)]` struct.
+///
+/// Creating a reference to an unaligned field is undefined behaviour, so unlike
+/// [`Accessor`] this type never hands out `&F` or `&mut F`. Values are moved in and
+/// out with `read_unaligned`/`write_unaligned` instead. `#[derive(Accessor)]`
+/// generates these for packed structs.
+///
+/// ```rust
+/// use pathmod_core::UnalignedAccessor;
+///
+/// #[repr(C, packed)]
+/// struct Header { tag: u8, len: u32 }
+/// let len: UnalignedAccessor<Header, u32> =
+///     unsafe { UnalignedAccessor::from_offset(core::mem::offset_of!(Header, len) as isize) };
+///
+/// let mut h = Header { tag: 1, len: 512 };
+/// assert_eq!(len.read(&h), 512);
+/// len.write(&mut h, 1024);
+/// assert_eq!({ h.len }, 1024);
+/// ```
+pub struct UnalignedAccessor<T, F> {
+    offset: isize,
+    _phantom: PhantomData<fn(T) -> F>,
+}
+
+impl<T, F> Clone for UnalignedAccessor<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for UnalignedAccessor<T, F> {}
+
+impl<T, F> core::fmt::Debug for UnalignedAccessor<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UnalignedAccessor")
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<T, F> UnalignedAccessor<T, F> {
+    /// Construct from a precomputed byte offset.
+    ///
+    /// # Safety
+    /// `offset` must be the byte distance from the start of `T` to a field of type `F`,
+    /// as for [`Accessor::from_offset`], except that the field need not be aligned.
+    pub const unsafe fn from_offset(offset: isize) -> Self {
+        Self {
+            offset,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The byte offset from the start of `T` to the focused field `F`.
+    pub const fn offset(&self) -> isize {
+        self.offset
+    }
+
+    /// Copy the focused value out.
+    pub fn read(&self, root: &T) -> F
+    where
+        F: Copy,
+    {
+        // SAFETY: the offset leads to an initialized `F` inside `root`; reading it
+        // unaligned is allowed, and `F: Copy` means the copy does not duplicate
+        // ownership.
+        unsafe {
+            let ptr = (root as *const T as *const u8).offset(self.offset) as *const F;
+            ptr.read_unaligned()
+        }
+    }
+
+    /// Move `value` into the focused location, returning the previous value.
+    pub fn replace(&self, root: &mut T, value: F) -> F {
+        // SAFETY: as in `read`, with exclusive access. The old value is moved out
+        // before the new one is written over it, so nothing is dropped twice.
+        unsafe {
+            let ptr = (root as *mut T as *mut u8).offset(self.offset) as *mut F;
+            let old = ptr.read_unaligned();
+            ptr.write_unaligned(value);
+            old
+        }
+    }
+
+    /// Move `value` into the focused location, dropping the previous value.
+    pub fn write(&self, root: &mut T, value: F) {
+        drop(self.replace(root, value));
+    }
+
+    /// Replace the focused value with the result of `f` applied to a copy of it.
+    pub fn update(&self, root: &mut T, f: impl FnOnce(F) -> F)
+    where
+        F: Copy,
+    {
+        self.write(root, f(self.read(root)));
+    }
+
+    /// Compose with a further unaligned accessor, yielding one from `T` to `V`.
+    ///
+    /// Use [`Accessor::unaligned`] to continue with an ordinary accessor: once a path
+    /// has crossed a packed struct, nothing after it is known to be aligned.
+    pub const fn compose<V>(self, next: UnalignedAccessor<F, V>) -> UnalignedAccessor<T, V> {
+        // SAFETY: offsets add, as for `Accessor::compose`.
+        unsafe { UnalignedAccessor::from_offset(self.offset + next.offset) }
+    }
+}
+
+impl<T, F> Accessor<T, F> {
+    /// View this accessor as an [`UnalignedAccessor`], which is always sound.
+    pub const fn unaligned(self) -> UnalignedAccessor<T, F> {
+        // SAFETY: an aligned field is in particular a valid possibly-unaligned one.
+        unsafe { UnalignedAccessor::from_offset(self.offset()) }
+    }
+
+    /// Continue into a packed struct with an [`UnalignedAccessor`].
+    pub const fn compose_unaligned<V>(
+        self,
+        next: UnalignedAccessor<F, V>,
+    ) -> UnalignedAccessor<T, V> {
+        self.unaligned().compose(next)
+    }
+}
+
+impl<T, F> From<Accessor<T, F>> for UnalignedAccessor<T, F> {
+    fn from(acc: Accessor<T, F>) -> Self {
+        acc.unaligned()
+    }
+}
@@ -0,0 +1,280 @@
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+use crate::Accessor;
+
+impl<T: ?Sized, F> Accessor<T, F> {
+    /// Project a raw pointer to a `T` to a raw pointer to the focused field.
+    ///
+    /// Nothing is read, and `root` need not be valid or initialized: this only does
+    /// the offset arithmetic, so it is the building block for initializing a
+    /// `MaybeUninit<T>` field by field. The result is only dereferenceable if `root`
+    /// points to an allocation holding a `T`.
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use pathmod_core::field;
+    ///
+    /// struct Frame { id: u32, data: [u8; 1024] }
+    ///
+    /// let mut frame = Box::<Frame>::new_uninit();
+    /// let root = frame.as_mut_ptr();
+    /// // SAFETY: both fields are written before `assume_init`.
+    /// let frame = unsafe {
+    ///     field!(Frame, id).project_ptr(root).write(7);
+    ///     field!(Frame, data).project_ptr(root).write_bytes(0, 1);
+    ///     frame.assume_init()
+    /// };
+    /// assert_eq!((frame.id, frame.data[1023]), (7, 0));
+    /// ```
+    pub fn project_ptr(&self, root: *mut T) -> *mut F {
+        root.cast::<u8>().wrapping_offset(self.offset).cast::<F>()
+    }
+}
+
+impl<T, F> Accessor<T, F> {
+    /// Borrow the focused field of a possibly uninitialized `T` as a
+    /// `MaybeUninit<F>`.
+    pub fn project_uninit<'a>(&self, root: &'a mut MaybeUninit<T>) -> &'a mut MaybeUninit<F> {
+        // SAFETY: `MaybeUninit<T>` has the layout of `T`, so the offset leads to a
+        // properly aligned `F`-sized region inside it, and any bytes are a valid
+        // `MaybeUninit<F>`.
+        unsafe { &mut *self.project_ptr(root.as_mut_ptr()).cast::<MaybeUninit<F>>() }
+    }
+}
+
+/// Field metadata for building a `Self` with [`UninitBuilder`], emitted by
+/// `#[derive(Accessor)]` for structs marked `#[pathmod(uninit)]`.
+///
+/// # Safety
+/// `FIELD_NAMES` must list every field of `Self`, in declaration order, and hold at
+/// most 64 entries. `drop_field(this, i)` must drop exactly the field at index `i` in
+/// place, given that `this` points to a `Self` whose field `i` is initialized.
+pub unsafe trait InitFields: Sized {
+    /// The field names, or indices for tuple fields, in declaration order.
+    const FIELD_NAMES: &'static [&'static str];
+
+    /// Drop the field at `index` in place.
+    ///
+    /// # Safety
+    /// `this` must point to a `Self` whose field `index` is initialized and not
+    /// used again afterwards.
+    unsafe fn drop_field(this: *mut Self, index: usize);
+}
+
+/// A field of `T` that [`UninitBuilder`] can write: an accessor plus the field's
+/// position among `T`'s fields.
+///
+/// Obtained from the derived `init_<field>()` methods.
+pub struct InitField<T, F> {
+    acc: Accessor<T, F>,
+    index: usize,
+}
+
+impl<T, F> Clone for InitField<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for InitField<T, F> {}
+
+impl<T, F> core::fmt::Debug for InitField<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InitField")
+            .field("offset", &self.acc.offset())
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<T: InitFields, F> InitField<T, F> {
+    /// Pair an accessor with the index of the field it focuses.
+    ///
+    /// # Safety
+    /// `acc` must focus exactly the whole field at `index` in
+    /// [`InitFields::FIELD_NAMES`], which has type `F`.
+    pub const unsafe fn new(acc: Accessor<T, F>, index: usize) -> Self {
+        Self { acc, index }
+    }
+
+    /// The accessor to the field.
+    pub const fn accessor(self) -> Accessor<T, F> {
+        self.acc
+    }
+
+    /// The field name, or index for a tuple field.
+    pub fn name(self) -> &'static str {
+        T::FIELD_NAMES[self.index]
+    }
+}
+
+/// Builds a `T` in place, on the heap, one field at a time.
+///
+/// The builder tracks which fields have been written in a bitset and only hands out
+/// the `T` once every field is initialized. Dropping a half-built value drops exactly
+/// the fields written so far. Fields are named by the `init_<field>()` methods that
+/// `#[derive(Accessor)]` generates for structs marked `#[pathmod(uninit)]`.
+///
+/// ```rust
+/// use pathmod_core::{InitField, InitFields, UninitBuilder, field};
+///
+/// struct Frame { id: u32, data: [u8; 4096] }
+/// // SAFETY: the names and drops match the fields of `Frame`.
+/// unsafe impl InitFields for Frame {
+///     const FIELD_NAMES: &'static [&'static str] = &["id", "data"];
+///     unsafe fn drop_field(this: *mut Self, index: usize) {
+///         match index {
+///             0 => unsafe { core::ptr::drop_in_place(&raw mut (*this).id) },
+///             _ => unsafe { core::ptr::drop_in_place(&raw mut (*this).data) },
+///         }
+///     }
+/// }
+/// // SAFETY: each accessor focuses the field at that index.
+/// let (id, data) = unsafe {
+///     (InitField::new(field!(Frame, id), 0), InitField::new(field!(Frame, data), 1))
+/// };
+///
+/// let mut b = UninitBuilder::<Frame>::new();
+/// b.write(id, 7);
+/// assert_eq!(b.missing().collect::<Vec<_>>(), ["data"]);
+///
+/// b.uninit_mut(data).write([1; 4096]);
+/// // SAFETY: `data` was just written in full.
+/// unsafe { b.assume_written(data) };
+/// let frame: Box<Frame> = b.try_build_boxed().expect("every field is written");
+/// assert_eq!((frame.id, frame.data[4095]), (7, 1));
+/// ```
+pub struct UninitBuilder<T: InitFields> {
+    value: Box<MaybeUninit<T>>,
+    /// Bit `i` is set when field `i` is initialized.
+    written: u64,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: InitFields> Default for UninitBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: InitFields> UninitBuilder<T> {
+    /// Allocate an uninitialized `T` with no fields written.
+    pub fn new() -> Self {
+        const {
+            assert!(
+                T::FIELD_NAMES.len() <= 64,
+                "UninitBuilder supports at most 64 fields"
+            )
+        };
+        Self {
+            value: Box::new_uninit(),
+            written: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Move `value` into `field`, dropping any value written to it before.
+    pub fn write<F>(&mut self, field: InitField<T, F>, value: F) -> &mut Self {
+        self.uninit_mut(field).write(value);
+        self.written |= 1 << field.index;
+        self
+    }
+
+    /// By-value form of [`write`](Self::write), for chaining.
+    pub fn with<F>(mut self, field: InitField<T, F>, value: F) -> Self {
+        self.write(field, value);
+        self
+    }
+
+    /// The storage of `field`, to initialize it in place (e.g. a large array)
+    /// before calling [`assume_written`](Self::assume_written).
+    ///
+    /// If `field` was already written, its value is dropped first, and the field
+    /// counts as missing again until it is marked written.
+    pub fn uninit_mut<F>(&mut self, field: InitField<T, F>) -> &mut MaybeUninit<F> {
+        let written = self.is_written(field);
+        self.written &= !(1 << field.index);
+        let slot = field.acc.project_uninit(&mut self.value);
+        if written {
+            // SAFETY: the field was initialized, as recorded in `written`, and is now
+            // recorded as uninitialized.
+            unsafe { slot.assume_init_drop() };
+        }
+        slot
+    }
+
+    /// Record `field` as initialized.
+    ///
+    /// # Safety
+    /// The field must hold a valid `F`, written through
+    /// [`uninit_mut`](Self::uninit_mut) or [`as_mut_ptr`](Self::as_mut_ptr).
+    pub unsafe fn assume_written<F>(&mut self, field: InitField<T, F>) {
+        self.written |= 1 << field.index;
+    }
+
+    /// A raw pointer to the `T` under construction, e.g. for
+    /// [`Accessor::project_ptr`] into nested fields.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.value.as_mut_ptr()
+    }
+
+    /// Whether `field` has been written.
+    pub fn is_written<F>(&self, field: InitField<T, F>) -> bool {
+        self.written & (1 << field.index) != 0
+    }
+
+    /// Borrow `field` mutably if it has been written.
+    pub fn get_mut<F>(&mut self, field: InitField<T, F>) -> Option<&mut F> {
+        if !self.is_written(field) {
+            return None;
+        }
+        // SAFETY: the field is initialized, as recorded in `written`.
+        Some(unsafe { field.acc.project_uninit(&mut self.value).assume_init_mut() })
+    }
+
+    /// The names of the fields not written yet, in declaration order.
+    pub fn missing(&self) -> impl Iterator<Item = &'static str> + '_ {
+        T::FIELD_NAMES
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.written & (1 << i) == 0)
+            .map(|(_, name)| *name)
+    }
+
+    /// The finished value on the heap, or the builder back if a field is missing.
+    pub fn try_build_boxed(self) -> Result<Box<T>, Self> {
+        if self.missing().next().is_some() {
+            return Err(self);
+        }
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: every field is initialized, so the `T` is; `this` is not dropped,
+        // so the box is moved out exactly once.
+        Ok(unsafe { core::ptr::read(&this.value).assume_init() })
+    }
+
+    /// The finished value, or the builder back if a field is missing.
+    pub fn try_build(self) -> Result<T, Self> {
+        self.try_build_boxed().map(|value| *value)
+    }
+}
+
+impl<T: InitFields> Drop for UninitBuilder<T> {
+    fn drop(&mut self) {
+        let root = self.value.as_mut_ptr();
+        for index in (0..T::FIELD_NAMES.len()).filter(|i| self.written & (1 << i) != 0) {
+            // SAFETY: the field is initialized, as recorded in `written`, and the
+            // builder is never used again.
+            unsafe { T::drop_field(root, index) };
+        }
+    }
+}
+
+impl<T: InitFields> core::fmt::Debug for UninitBuilder<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UninitBuilder")
+            .field("type", &core::any::type_name::<T>())
+            .field("missing", &self.missing().collect::<Vec<_>>())
+            .finish()
+    }
+}
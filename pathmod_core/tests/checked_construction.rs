@@ -0,0 +1,116 @@
+use pathmod_core::{field, Accessor, OffsetError};
+
+#[derive(Debug, PartialEq)]
+struct Inner {
+    flag: bool,
+    value: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Outer {
+    id: u64,
+    pair: (u8, Inner),
+}
+
+#[repr(C)]
+struct Layout {
+    a: u16,
+    b: u32,
+}
+
+const VALUE: Accessor<Outer, u32> = field!(Outer, pair.1.value);
+
+fn outer() -> Outer {
+    Outer {
+        id: 1,
+        pair: (
+            2,
+            Inner {
+                flag: true,
+                value: 3,
+            },
+        ),
+    }
+}
+
+#[test]
+fn field_macro_builds_nested_accessors() {
+    let mut o = outer();
+    assert_eq!(*VALUE.get(&o), 3);
+    VALUE.set(&mut o, 30);
+    assert_eq!(o.pair.1.value, 30);
+
+    let flag = field!(Outer, pair.1).compose(field!(Inner, flag));
+    flag.set_mut(&mut o, |f| *f = !*f);
+    assert!(!o.pair.1.flag);
+    assert_eq!(
+        field!(Outer, pair.1.flag).offset(),
+        flag.offset(),
+        "one nested offset_of! equals the composed offsets"
+    );
+}
+
+#[test]
+fn field_macro_matches_offset_of() {
+    assert_eq!(
+        field!(Outer, id).offset(),
+        core::mem::offset_of!(Outer, id) as isize
+    );
+    assert_eq!(
+        field!(Outer, pair.0).offset(),
+        core::mem::offset_of!(Outer, pair.0) as isize
+    );
+}
+
+#[test]
+fn checked_offset_accepts_real_fields() {
+    let b = unsafe { Accessor::<Layout, u32>::from_offset_checked(4) }.unwrap();
+    let mut l = Layout { a: 1, b: 2 };
+    *b.get_mut(&mut l) += 40;
+    assert_eq!(l.b, 42);
+    assert_eq!(l.a, 1);
+
+    let a = unsafe { Accessor::<Layout, u16>::from_offset_checked(0) }.unwrap();
+    assert_eq!(*a.get(&l), 1);
+}
+
+#[test]
+fn checked_offset_rejects_out_of_bounds() {
+    let err = unsafe { Accessor::<Layout, u32>::from_offset_checked(6) }.unwrap_err();
+    assert_eq!(
+        err,
+        OffsetError::OutOfBounds {
+            offset: 6,
+            field_size: 4,
+            root_size: 8
+        }
+    );
+    assert!(unsafe { Accessor::<Layout, u32>::from_offset_checked(-4) }.is_err());
+    assert!(unsafe { Accessor::<Layout, u64>::from_offset_checked(0) }.is_err());
+    assert_eq!(
+        err.to_string(),
+        "field of 4 bytes at offset 6 does not fit in a root of 8 bytes"
+    );
+}
+
+#[test]
+fn checked_offset_rejects_misalignment() {
+    let err = unsafe { Accessor::<Layout, u32>::from_offset_checked(2) }.unwrap_err();
+    assert_eq!(
+        err,
+        OffsetError::Misaligned {
+            offset: 2,
+            field_align: 4,
+            root_align: 4
+        }
+    );
+    // A root with weaker alignment cannot guarantee the field's alignment.
+    assert!(unsafe { Accessor::<[u8; 8], u32>::from_offset_checked(4) }.is_err());
+}
+
+#[test]
+fn checked_offset_is_const() {
+    const B: Result<Accessor<Layout, u32>, OffsetError> =
+        unsafe { Accessor::from_offset_checked(4) };
+    assert!(B.is_ok());
+}
@@ -0,0 +1,94 @@
+use pathmod_core::{Accessor, Optional, Prism};
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Dot(Point),
+    Label(String),
+}
+
+#[derive(Debug, PartialEq)]
+struct Canvas {
+    id: u8,
+    shape: Shape,
+}
+
+fn prism_dot() -> Prism<Shape, Point> {
+    Prism::new(
+        |s| if let Shape::Dot(p) = s { Some(p) } else { None },
+        |s| if let Shape::Dot(p) = s { Some(p) } else { None },
+        Shape::Dot,
+    )
+}
+
+fn acc_shape() -> Accessor<Canvas, Shape> {
+    unsafe { Accessor::from_offset(core::mem::offset_of!(Canvas, shape) as isize) }
+}
+
+fn acc_y() -> Accessor<Point, i32> {
+    unsafe { Accessor::from_offset(core::mem::offset_of!(Point, y) as isize) }
+}
+
+#[test]
+fn prism_preview_review_and_set() {
+    let p = prism_dot();
+    let mut s = Shape::Label("a".into());
+    assert!(p.preview(&s).is_none());
+    assert!(!p.matches(&s));
+    assert!(!p.set_mut(&mut s, |pt| pt.x = 1));
+
+    p.set(&mut s, Point { x: 1, y: 2 });
+    assert!(p.matches(&s));
+    assert!(p.set_mut(&mut s, |pt| pt.x += 10));
+    assert_eq!(p.preview(&s), Some(&Point { x: 11, y: 2 }));
+    p.preview_mut(&mut s).unwrap().y = 5;
+    assert_eq!(s, Shape::Dot(Point { x: 11, y: 5 }));
+
+    assert_eq!(
+        p.review(Point { x: 0, y: 0 }),
+        Shape::Dot(Point { x: 0, y: 0 })
+    );
+}
+
+#[test]
+fn accessor_then_prism_then_accessor() {
+    let dot_y = acc_shape().compose_prism(prism_dot()).compose(acc_y());
+    let mut c = Canvas {
+        id: 1,
+        shape: Shape::Dot(Point { x: 1, y: 2 }),
+    };
+
+    assert_eq!(dot_y.get(&c), Some(&2));
+    assert!(dot_y.set(&mut c, 7));
+    assert!(dot_y.set_mut(&mut c, |v| *v *= 2));
+    let next = *dot_y.get(&c).unwrap() + 1;
+    assert!(dot_y.set_clone(&mut c, &next));
+    assert_eq!(c.shape, Shape::Dot(Point { x: 1, y: 15 }));
+
+    // Switching variants makes the focus absent and writes become no-ops
+    c.shape = Shape::Label("l".into());
+    assert!(!dot_y.is_present(&c));
+    assert_eq!(dot_y.get_mut(&mut c), None);
+    assert!(!dot_y.set(&mut c, 1));
+    assert_eq!(c.shape, Shape::Label("l".into()));
+    assert_eq!(c.id, 1);
+}
+
+#[test]
+fn prism_then_accessor_and_optional_clone() {
+    let y: Optional<Shape, i32> = prism_dot().compose(acc_y());
+    let y2 = y.clone();
+    let mut s = Shape::Dot(Point { x: 0, y: 3 });
+    assert_eq!(y2.get(&s), Some(&3));
+    assert!(y.set(&mut s, 4));
+    assert_eq!(y2.get(&s), Some(&4));
+
+    // From conversions: an accessor is an always-present optional
+    let always: Optional<Point, i32> = acc_y().into();
+    assert_eq!(always.get(&Point { x: 0, y: 9 }), Some(&9));
+}
@@ -3,9 +3,10 @@
 //! This crate provides two derives:
 //! - `#[derive(Accessor)]` for structs (named or tuple), generating const field accessors
 //!   like `acc_<field>()` or `acc_<idx>()`, plus reconstruction helpers `with_*`.
-//! - `#[derive(EnumAccess)]` for enums (MVP: tuple variants with exactly one field),
+//! - `#[derive(EnumAccess)]` for enums with unit, tuple and struct-like variants,
 //!   generating helpers like `is_<variant>`, `as_<variant>`, `as_<variant>_mut`,
-//!   `set_<variant>`, and `map_<variant>`.
+//!   `set_<variant>`, `map_<variant>` and per-field `as_<variant>_<field>`, plus a
+//!   `prism_<variant>()` for single-field variants that composes with struct accessors.
 //!
 //! Most users should depend on the re-export crate `pathmod` and import:
 //! ```rust
@@ -28,7 +29,7 @@
 //! assert_eq!(foo.b.x, 7);
 //! ```
 //!
-//! Example — enum helpers (tuple single-field variants)
+//! Example — enum helpers
 //! ```rust
 //! use pathmod::prelude::*;
 //!
@@ -41,91 +42,710 @@
 //! assert_eq!(m, Msg::Int(15));
 //! m.set_text("hi".to_string());
 //! assert!(m.is_text());
+//!
+//! // Prisms compose with struct accessors into an optional focus
+//! #[derive(Accessor)]
+//! struct Envelope { msg: Msg }
+//!
+//! let mut env = Envelope { msg: m };
+//! let text = Envelope::acc_msg().compose_prism(Msg::prism_text());
+//! assert_eq!(text.get(&env).map(String::as_str), Some("hi"));
+//! assert!(text.set(&mut env, "bye".to_string()));
+//!
+//! // Unit, multi-field and struct-like variants are supported too
+//! #[derive(EnumAccess)]
+//! enum Shape { Empty, Line(i32, i32), Rect { w: u32, h: u32 } }
+//!
+//! let mut s = Shape::Rect { w: 2, h: 3 };
+//! assert_eq!(s.as_rect(), Some((&2, &3)));
+//! *s.as_rect_h_mut().unwrap() = 4;
+//! s.map_rect(|w, h| *w *= *h);
+//! assert_eq!(s.as_rect_w(), Some(&8));
+//! s.set_empty();
+//! assert!(s.is_empty() && s.as_line_0().is_none());
 //! ```
 
 extern crate proc_macro;
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
+use syn::ext::IdentExt;
 use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident};
 
+/// Whether `ty` mentions any of the type parameters in `generics`.
+fn mentions_type_param(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    fn walk(ts: proc_macro2::TokenStream, params: &[&Ident]) -> bool {
+        ts.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(i) => params.iter().any(|p| **p == i),
+            proc_macro2::TokenTree::Group(g) => walk(g.stream(), params),
+            _ => false,
+        })
+    }
+    let params: Vec<&Ident> = generics.type_params().map(|p| &p.ident).collect();
+    !params.is_empty() && walk(quote!(#ty), &params)
+}
+
+/// Emit the `pathmod::FieldTable` impl describing `fields` (name, member, type).
+fn field_table(
+    ty_ident: &Ident,
+    generics: &syn::Generics,
+    fields: &[(String, syn::Member, &syn::Type)],
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| syn::WhereClause {
+        where_token: Default::default(),
+        predicates: Default::default(),
+    });
+    for (_, _, fty) in fields {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#fty: 'static));
+    }
+    where_clause
+        .predicates
+        .push(syn::parse_quote!(Self: 'static));
+    let infos = fields.iter().map(|(name, member, fty)| {
+        // Nested tables are found by autoref probing, which only works for concrete
+        // types; fields whose type depends on a type parameter are treated as leaves.
+        let nested = if mentions_type_param(fty, generics) {
+            quote! { || None }
+        } else {
+            quote! {
+                || {
+                    use pathmod::__private::{ViaFieldTable as _, ViaNoFieldTable as _};
+                    (&&pathmod::__private::TableProbe::<#fty>::new()).field_table()
+                }
+            }
+        };
+        quote! {
+            pathmod::FieldInfo::new::<#fty>(#name, core::mem::offset_of!(Self, #member), #nested)
+        }
+    });
+    quote! {
+        // SAFETY: offsets and types are taken from the fields of this very struct.
+        unsafe impl #impl_generics pathmod::FieldTable for #ty_ident #ty_generics #where_clause {
+            const FIELDS: &'static [pathmod::FieldInfo] = &[#(#infos),*];
+        }
+    }
+}
+
+/// Parse the `key = "value"` and bare `flag` entries of `#[pathmod(...)]` attributes,
+/// rejecting anything not listed in `keys` or `flags`. Flags come back without a value;
+/// a name may be listed in both.
+fn pathmod_attrs(
+    attrs: &[syn::Attribute],
+    keys: &[&str],
+    flags: &[&str],
+) -> syn::Result<Vec<(Ident, Option<syn::LitStr>)>> {
+    let mut entries = Vec::new();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("pathmod")) {
+        attr.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(key) if keys.iter().any(|k| key == k) && meta.input.peek(syn::Token![=]) => {
+                entries.push((key.clone(), Some(meta.value()?.parse()?)));
+                Ok(())
+            }
+            Some(flag) if flags.iter().any(|f| flag == f) => {
+                entries.push((flag.clone(), None));
+                Ok(())
+            }
+            _ => {
+                let expected: Vec<String> = keys
+                    .iter()
+                    .map(|k| format!("`{k} = \"...\"`"))
+                    .chain(flags.iter().map(|f| format!("`{f}`")))
+                    .collect();
+                Err(meta.error(format!(
+                    "unsupported pathmod attribute, expected {}",
+                    expected.join(" or ")
+                )))
+            }
+        })?;
+    }
+    Ok(entries)
+}
+
+/// Parse `#[pathmod(vis = "...")]` from `attrs`, if present, also allowing `flags`.
+fn vis_override(attrs: &[syn::Attribute], flags: &[&str]) -> syn::Result<Option<syn::Visibility>> {
+    let mut vis = None;
+    for lit in pathmod_attrs(attrs, &["vis"], flags)?
+        .into_iter()
+        .filter_map(|(_, lit)| lit)
+    {
+        let parsed = lit.parse::<syn::Visibility>().map_err(|_| {
+            syn::Error::new(
+                lit.span(),
+                "invalid visibility, expected e.g. `pub`, `pub(crate)` or `pub(super)`",
+            )
+        })?;
+        vis = Some(parsed);
+    }
+    Ok(vis)
+}
+
+/// Visibility of the items generated for each field: a field-level
+/// `#[pathmod(vis = "...")]`, else the struct-level one, else the field's own.
+fn field_visibilities(
+    attrs: &[syn::Attribute],
+    fields: &Fields,
+) -> syn::Result<Vec<syn::Visibility>> {
+    let struct_vis = vis_override(attrs, &["uninit"])?;
+    fields
+        .iter()
+        .map(|f| {
+            Ok(vis_override(&f.attrs, &["pin"])?
+                .or_else(|| struct_vis.clone())
+                .unwrap_or_else(|| f.vis.clone()))
+        })
+        .collect()
+}
+
+/// Whether every place that can name a type with visibility `ty_vis` can also see a
+/// field with visibility `field_vis`. Trait impls are usable wherever their type is,
+/// so only such fields may appear in the type's `FieldTable`. A `pub(in path)` that
+/// differs from the type's own is ranked pessimistically on both sides.
+fn vis_covers(field_vis: &syn::Visibility, ty_vis: &syn::Visibility) -> bool {
+    fn rank(vis: &syn::Visibility, widest: bool) -> u8 {
+        match vis {
+            syn::Visibility::Public(_) => 4,
+            syn::Visibility::Inherited => 0,
+            syn::Visibility::Restricted(r) if r.in_token.is_none() => {
+                if r.path.is_ident("crate") {
+                    3
+                } else if r.path.is_ident("super") {
+                    2
+                } else {
+                    0
+                }
+            }
+            syn::Visibility::Restricted(_) => {
+                if widest {
+                    3
+                } else {
+                    1
+                }
+            }
+        }
+    }
+    quote!(#field_vis).to_string() == quote!(#ty_vis).to_string()
+        || rank(field_vis, false) >= rank(ty_vis, true)
+}
+
+/// Whether `ty` is syntactically unsized: a slice, `str`, a trait object, or a type
+/// parameter declared `?Sized`. Only the last field of a struct can be unsized.
+fn is_unsized(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    fn relaxes_sized(
+        bounds: &syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>,
+    ) -> bool {
+        bounds.iter().any(|b| {
+            matches!(b, syn::TypeParamBound::Trait(t)
+                if matches!(t.modifier, syn::TraitBoundModifier::Maybe(_)) && t.path.is_ident("Sized"))
+        })
+    }
+    match ty {
+        syn::Type::Slice(_) | syn::Type::TraitObject(_) => true,
+        syn::Type::Paren(p) => is_unsized(&p.elem, generics),
+        syn::Type::Path(p) if p.qself.is_none() => {
+            let Some(ident) = p.path.get_ident() else {
+                return false;
+            };
+            if ident == "str" {
+                return true;
+            }
+            let in_params = generics
+                .type_params()
+                .any(|tp| tp.ident == *ident && relaxes_sized(&tp.bounds));
+            let in_where = generics
+                .where_clause
+                .iter()
+                .flat_map(|w| &w.predicates)
+                .any(|pred| {
+                    matches!(pred, syn::WherePredicate::Type(pt)
+                    if matches!(&pt.bounded_ty, syn::Type::Path(bp) if bp.path.is_ident(ident))
+                        && relaxes_sized(&pt.bounds))
+                });
+            in_params || in_where
+        }
+        _ => false,
+    }
+}
+
+/// Emit `acc_<field>()` returning a `pathmod::TailAccessor` for an unsized last field.
+fn tail_accessor(
+    ty_ident: &Ident,
+    ty_generics: &syn::TypeGenerics,
+    vis: &syn::Visibility,
+    acc_fn: &Ident,
+    member: &syn::Member,
+    fty: &syn::Type,
+) -> proc_macro2::TokenStream {
+    quote! {
+        /// Accessor to the unsized last field.
+        #vis const fn #acc_fn() -> pathmod::TailAccessor<#ty_ident #ty_generics, #fty> {
+            let project: fn(*mut Self) -> *mut #fty = |p| unsafe { &raw mut (*p).#member };
+            // SAFETY: the projection only computes the address of the field.
+            unsafe { pathmod::TailAccessor::from_projection(project) }
+        }
+    }
+}
+
+/// Whether `attrs` contain `#[repr(packed)]` or `#[repr(packed(N))]`.
+fn is_packed(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("repr"))
+        .filter_map(|a| a.meta.require_list().ok())
+        .any(|list| {
+            list.tokens
+                .clone()
+                .into_iter()
+                .any(|tt| matches!(tt, proc_macro2::TokenTree::Ident(ref i) if i == "packed"))
+        })
+}
+
+/// Emit the pin projection for a struct with `#[pathmod(pin)]` fields: a
+/// `pin_<field>()` accessor per pinned field, `project_pin` with its projection
+/// struct, and the `Unpin` impl and `Drop` guard that make the projection sound.
+/// Returns nothing if no field is pinned.
+fn pin_projection(
+    input: &DeriveInput,
+    fields: &Fields,
+    vis: &[syn::Visibility],
+    packed: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut pinned = Vec::new();
+    for f in fields {
+        let pin = pathmod_attrs(&f.attrs, &["vis"], &["pin"])?
+            .iter()
+            .any(|(name, lit)| name == "pin" && lit.is_none());
+        if pin && is_unsized(&f.ty, &input.generics) {
+            return Err(syn::Error::new_spanned(
+                &f.ty,
+                "#[pathmod(pin)] is not supported on unsized fields",
+            ));
+        }
+        pinned.push(pin);
+    }
+    if !pinned.contains(&true) {
+        return Ok(quote! {});
+    }
+    if packed {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[pathmod(pin)] cannot be used on #[repr(packed)] structs",
+        ));
+    }
+
+    let ty_ident = &input.ident;
+    let struct_vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let proj_ident = format_ident!("{}Projection", ty_ident);
+    let proj_doc =
+        format!("Pinned projection of [`{ty_ident}`], returned by `{ty_ident}::project_pin`.");
+    let mut proj_generics = input.generics.clone();
+    proj_generics.params.insert(0, syn::parse_quote!('__pin));
+    let (_, proj_ty_generics, proj_where_clause) = proj_generics.split_for_impl();
+
+    let members: Vec<syn::Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(i.into()),
+        })
+        .collect();
+    let proj_types = fields.iter().zip(&pinned).map(|(f, pin)| {
+        let fty = &f.ty;
+        if *pin {
+            quote!(core::pin::Pin<&'__pin mut #fty>)
+        } else {
+            quote!(&'__pin mut #fty)
+        }
+    });
+    let proj_fields = fields
+        .iter()
+        .zip(vis)
+        .zip(proj_types)
+        .map(|((f, vis), pty)| {
+            let name = f.ident.iter();
+            quote!(#vis #(#name:)* #pty)
+        });
+    let proj_struct = match fields {
+        Fields::Named(_) => quote! {
+            #struct_vis struct #proj_ident #proj_generics #proj_where_clause { #(#proj_fields),* }
+        },
+        _ => quote! {
+            #struct_vis struct #proj_ident #proj_generics (#(#proj_fields),*) #proj_where_clause;
+        },
+    };
+    let proj_values = members.iter().zip(&pinned).map(|(member, pin)| {
+        if *pin {
+            quote!(#member: core::pin::Pin::new_unchecked(&mut this.#member))
+        } else {
+            quote!(#member: &mut this.#member)
+        }
+    });
+
+    let pin_fns = fields
+        .iter()
+        .zip(&members)
+        .zip(vis)
+        .zip(&pinned)
+        .filter(|(_, pin)| **pin)
+        .map(|(((f, member), vis), _)| {
+            let (pin_fn, acc_fn) = match member {
+                syn::Member::Named(ident) => (
+                    format_ident!("pin_{}", ident),
+                    format_ident!("acc_{}", ident),
+                ),
+                syn::Member::Unnamed(index) => (
+                    format_ident!("pin_{}", index.index),
+                    format_ident!("acc_{}", index.index),
+                ),
+            };
+            let fty = &f.ty;
+            let name = match member {
+                syn::Member::Named(ident) => ident.unraw().to_string(),
+                syn::Member::Unnamed(index) => index.index.to_string(),
+            };
+            let doc = format!("Pin accessor to the structurally pinned `{name}` field.");
+            quote! {
+                #[doc = #doc]
+                #vis const fn #pin_fn() -> pathmod::PinAccessor<#ty_ident #ty_generics, #fty> {
+                    // SAFETY: the field is marked `#[pathmod(pin)]`, and the derive only
+                    // implements `Unpin` when every pinned field is `Unpin`, forbids
+                    // `Drop` impls and rejects packed structs.
+                    unsafe { pathmod::PinAccessor::new(Self::#acc_fn()) }
+                }
+            }
+        });
+
+    let pinned_tys = fields
+        .iter()
+        .zip(&pinned)
+        .filter(|(_, pin)| **pin)
+        .map(|(f, _)| &f.ty);
+    let mut unpin_generics = proj_generics.clone();
+    unpin_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {
+            pathmod::__private::Wrapper<'__pin, (#(#pinned_tys,)*)>: core::marker::Unpin
+        });
+    let (unpin_impl_generics, _, unpin_where_clause) = unpin_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ty_ident #ty_generics #where_clause {
+            #(#pin_fns)*
+
+            /// Project a pinned reference into pinned references to the
+            /// `#[pathmod(pin)]` fields and plain mutable references to the others.
+            #struct_vis fn project_pin<'__pin>(
+                self: core::pin::Pin<&'__pin mut Self>,
+            ) -> #proj_ident #proj_ty_generics {
+                // SAFETY: pinned fields are only handed out pinned, and the other fields
+                // are never pinned, see `pin_*`.
+                unsafe {
+                    let this = core::pin::Pin::get_unchecked_mut(self);
+                    #proj_ident { #(#proj_values),* }
+                }
+            }
+        }
+
+        #[doc = #proj_doc]
+        #[allow(dead_code)]
+        #proj_struct
+
+        // `Self` is `Unpin` only if every pinned field is, whatever the other fields are.
+        impl #unpin_impl_generics core::marker::Unpin for #ty_ident #ty_generics #unpin_where_clause {}
+
+        // A `Drop` impl could move out of a pinned field; it would conflict with this one.
+        const _: () = {
+            trait MustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: core::ops::Drop> MustNotImplDrop for T {}
+            impl #impl_generics MustNotImplDrop for #ty_ident #ty_generics #where_clause {}
+        };
+    })
+}
+
+/// Emit `init_<field>()` handles and the `pathmod::InitFields` impl for a struct
+/// marked `#[pathmod(uninit)]`, so it can be built with `pathmod::UninitBuilder`.
+/// Returns nothing if the struct is not marked.
+fn uninit_support(
+    input: &DeriveInput,
+    fields: &Fields,
+    vis: &[syn::Visibility],
+    packed: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let Some((flag, _)) = pathmod_attrs(&input.attrs, &["vis"], &["uninit"])?
+        .into_iter()
+        .find(|(name, lit)| name == "uninit" && lit.is_none())
+    else {
+        return Ok(quote! {});
+    };
+    if packed {
+        return Err(syn::Error::new_spanned(
+            flag,
+            "#[pathmod(uninit)] cannot be used on #[repr(packed)] structs",
+        ));
+    }
+    if let Some(f) = fields.iter().find(|f| is_unsized(&f.ty, &input.generics)) {
+        return Err(syn::Error::new_spanned(
+            &f.ty,
+            "#[pathmod(uninit)] is not supported on structs with an unsized field",
+        ));
+    }
+    if fields.len() > 64 {
+        return Err(syn::Error::new_spanned(
+            flag,
+            "#[pathmod(uninit)] supports at most 64 fields",
+        ));
+    }
+
+    let ty_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut names = Vec::new();
+    let mut init_fns = Vec::new();
+    let mut drops = Vec::new();
+    for (i, (f, vis)) in fields.iter().zip(vis).enumerate() {
+        let (name, member) = match &f.ident {
+            Some(ident) => (ident.unraw().to_string(), syn::Member::Named(ident.clone())),
+            None => (i.to_string(), syn::Member::Unnamed(i.into())),
+        };
+        let init_fn = format_ident!("init_{}", name);
+        let acc_fn = format_ident!("acc_{}", name);
+        let fty = &f.ty;
+        let doc = format!("Handle to the `{name}` field for `pathmod::UninitBuilder`.");
+        init_fns.push(quote! {
+            #[doc = #doc]
+            #vis const fn #init_fn() -> pathmod::InitField<#ty_ident #ty_generics, #fty> {
+                // SAFETY: `#acc_fn` focuses the whole field at index #i.
+                unsafe { pathmod::InitField::new(Self::#acc_fn(), #i) }
+            }
+        });
+        drops.push(quote! {
+            #i => core::ptr::drop_in_place(&raw mut (*this).#member),
+        });
+        names.push(name);
+    }
+
+    Ok(quote! {
+        impl #impl_generics #ty_ident #ty_generics #where_clause {
+            #(#init_fns)*
+        }
+
+        // SAFETY: the names and indices follow the declaration order of the fields, and
+        // each index drops its own field.
+        unsafe impl #impl_generics pathmod::InitFields for #ty_ident #ty_generics #where_clause {
+            const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+
+            unsafe fn drop_field(this: *mut Self, index: usize) {
+                // SAFETY: guaranteed by the caller.
+                unsafe {
+                    match index {
+                        #(#drops)*
+                        _ => {}
+                    }
+                }
+            }
+        }
+    })
+}
+
 fn expand(input: DeriveInput) -> proc_macro2::TokenStream {
-    let ty_ident = input.ident;
+    let ty_ident = input.ident.clone();
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let vis = match &input.data {
+        Data::Struct(s) => match field_visibilities(&input.attrs, &s.fields) {
+            Ok(vis) => vis,
+            Err(e) => return e.to_compile_error(),
+        },
+        _ => Vec::new(),
+    };
+    // Fields of packed structs may be unaligned, so no reference to them may ever be
+    // formed: hand out by-value `UnalignedAccessor`s and skip the field table, whose
+    // dynamic accessors borrow fields.
+    let packed = is_packed(&input.attrs);
+    // An unsized tail is borrowed by reference, which a packed struct may leave
+    // unaligned, and `UnalignedAccessor` can only move sized values.
+    if let Data::Struct(s) = &input.data {
+        if let Some(f) = s
+            .fields
+            .iter()
+            .find(|f| packed && is_unsized(&f.ty, &input.generics))
+        {
+            return syn::Error::new_spanned(
+                &f.ty,
+                "#[derive(Accessor)] does not support an unsized field in a #[repr(packed)] struct",
+            )
+            .to_compile_error();
+        }
+    }
+    let accessor = if packed {
+        quote!(pathmod::UnalignedAccessor)
+    } else {
+        quote!(pathmod::Accessor)
+    };
+    let pin = match &input.data {
+        Data::Struct(s) => match pin_projection(&input, &s.fields, &vis, packed) {
+            Ok(pin) => pin,
+            Err(e) => return e.to_compile_error(),
+        },
+        _ => quote! {},
+    };
+    let uninit = match &input.data {
+        Data::Struct(s) => match uninit_support(&input, &s.fields, &vis, packed) {
+            Ok(uninit) => uninit,
+            Err(e) => return e.to_compile_error(),
+        },
+        _ => quote! {},
+    };
+    // `with_*` moves `self` and so needs `Self: Sized`. For an unsized tail that is a
+    // type parameter each method gets that bound; a tail that is always unsized rules
+    // `with_*` out entirely.
+    let tail = match &input.data {
+        Data::Struct(s) => s.fields.iter().last().map(|f| &f.ty),
+        _ => None,
+    };
+    let (with_enabled, with_where) = match tail {
+        Some(ty) if is_unsized(ty, &input.generics) => (
+            mentions_type_param(ty, &input.generics),
+            quote!(where Self: Sized),
+        ),
+        _ => (true, quote!()),
+    };
 
     match input.data {
         Data::Struct(ref s) => match s.fields {
             Fields::Named(ref fields_named) => {
-                let acc_fns = fields_named.named.iter().map(|f| {
+                let acc_fns = fields_named.named.iter().zip(&vis).map(|(f, vis)| {
                     let fname: &Ident = f.ident.as_ref().unwrap();
                     let acc_fn = format_ident!("acc_{}", fname);
                     let fty = &f.ty;
+                    if is_unsized(fty, &input.generics) {
+                        let member = syn::Member::Named(fname.clone());
+                        return tail_accessor(&ty_ident, &ty_generics, vis, &acc_fn, &member, fty);
+                    }
                     quote! {
                         /// Accessor to the `#fname` field.
-                        pub const fn #acc_fn() -> pathmod::Accessor<#ty_ident #ty_generics, #fty> {
+                        #vis const fn #acc_fn() -> #accessor<#ty_ident #ty_generics, #fty> {
                             let off = core::mem::offset_of!(#ty_ident #ty_generics, #fname) as isize;
                             // SAFETY: `off` is computed from the field offset within the same allocation.
-                            unsafe { pathmod::Accessor::<#ty_ident #ty_generics, #fty>::from_offset(off) }
+                            unsafe { #accessor::<#ty_ident #ty_generics, #fty>::from_offset(off) }
                         }
                     }
                 });
 
-                let with_fns = fields_named.named.iter().map(|f| {
-                    let fname: &Ident = f.ident.as_ref().unwrap();
-                    let with_fn = format_ident!("with_{}", fname);
-                    let fty = &f.ty;
-                    quote! {
-                        /// Return a new value with `#fname` replaced by `new_val`.
-                        ///
-                        /// This consumes `self` and reconstructs `Self` without cloning
-                        /// any other fields (they are moved). This is the building block
-                        /// for minimal-clone (actually zero-clone) reconstruction up the path.
-                        pub fn #with_fn(mut self, new_val: #fty) -> Self {
-                            self.#fname = new_val;
-                            self
+                let with_fns = fields_named
+                    .named
+                    .iter()
+                    .zip(&vis)
+                    .filter(|(f, _)| with_enabled && !is_unsized(&f.ty, &input.generics))
+                    .map(|(f, vis)| {
+                        let fname: &Ident = f.ident.as_ref().unwrap();
+                        let with_fn = format_ident!("with_{}", fname);
+                        let fty = &f.ty;
+                        quote! {
+                            /// Return a new value with `#fname` replaced by `new_val`.
+                            ///
+                            /// This consumes `self` and reconstructs `Self` without cloning
+                            /// any other fields (they are moved). This is the building block
+                            /// for minimal-clone (actually zero-clone) reconstruction up the path.
+                            #vis fn #with_fn(mut self, new_val: #fty) -> Self #with_where {
+                                self.#fname = new_val;
+                                self
+                            }
                         }
-                    }
-                });
+                    });
+
+                let table_fields: Vec<_> = fields_named
+                    .named
+                    .iter()
+                    .zip(&vis)
+                    .filter(|(f, vis)| {
+                        !is_unsized(&f.ty, &input.generics) && vis_covers(vis, &input.vis)
+                    })
+                    .map(|(f, _)| {
+                        let fname = f.ident.clone().unwrap();
+                        (fname.unraw().to_string(), syn::Member::Named(fname), &f.ty)
+                    })
+                    .collect();
+                let table = if packed {
+                    quote! {}
+                } else {
+                    field_table(&ty_ident, &input.generics, &table_fields)
+                };
 
                 quote! {
                     impl #impl_generics #ty_ident #ty_generics #where_clause {
                         #(#acc_fns)*
                         #(#with_fns)*
                     }
+                    #table
+                    #pin
+                    #uninit
                 }
             }
             Fields::Unnamed(ref fields_unnamed) => {
-                let acc_fns = fields_unnamed.unnamed.iter().enumerate().map(|(i, f)| {
+                let acc_fns = fields_unnamed.unnamed.iter().zip(&vis).enumerate().map(|(i, (f, vis))| {
                     let acc_fn = format_ident!("acc_{}", i);
                     let fty = &f.ty;
                     let index = syn::Index::from(i);
+                    if is_unsized(fty, &input.generics) {
+                        let member = syn::Member::Unnamed(index);
+                        return tail_accessor(&ty_ident, &ty_generics, vis, &acc_fn, &member, fty);
+                    }
                     quote! {
                         /// Accessor to the tuple field at index #i.
-                        pub const fn #acc_fn() -> pathmod::Accessor<#ty_ident #ty_generics, #fty> {
+                        #vis const fn #acc_fn() -> #accessor<#ty_ident #ty_generics, #fty> {
                             let off = core::mem::offset_of!(#ty_ident #ty_generics, #index) as isize;
                             // SAFETY: `off` is computed from the field offset within the same allocation.
-                            unsafe { pathmod::Accessor::<#ty_ident #ty_generics, #fty>::from_offset(off) }
+                            unsafe { #accessor::<#ty_ident #ty_generics, #fty>::from_offset(off) }
                         }
                     }
                 });
-                let with_fns = fields_unnamed.unnamed.iter().enumerate().map(|(i, f)| {
-                    let with_fn = format_ident!("with_{}", i);
-                    let fty = &f.ty;
-                    let index = syn::Index::from(i);
-                    quote! {
-                        /// Return a new value with tuple field at index #i replaced by `new_val`.
-                        ///
-                        /// Consumes `self` and reconstructs `Self` without cloning other fields.
-                        pub fn #with_fn(mut self, new_val: #fty) -> Self {
-                            self.#index = new_val;
-                            self
+                let with_fns = fields_unnamed
+                    .unnamed
+                    .iter()
+                    .zip(&vis)
+                    .enumerate()
+                    .filter(|(_, (f, _))| with_enabled && !is_unsized(&f.ty, &input.generics))
+                    .map(|(i, (f, vis))| {
+                        let with_fn = format_ident!("with_{}", i);
+                        let fty = &f.ty;
+                        let index = syn::Index::from(i);
+                        quote! {
+                            /// Return a new value with tuple field at index #i replaced by `new_val`.
+                            ///
+                            /// Consumes `self` and reconstructs `Self` without cloning other fields.
+                            #vis fn #with_fn(mut self, new_val: #fty) -> Self #with_where {
+                                self.#index = new_val;
+                                self
+                            }
                         }
-                    }
-                });
+                    });
+                let table_fields: Vec<_> = fields_unnamed
+                    .unnamed
+                    .iter()
+                    .zip(&vis)
+                    .enumerate()
+                    .filter(|(_, (f, vis))| {
+                        !is_unsized(&f.ty, &input.generics) && vis_covers(vis, &input.vis)
+                    })
+                    .map(|(i, (f, _))| (i.to_string(), syn::Member::Unnamed(i.into()), &f.ty))
+                    .collect();
+                let table = if packed {
+                    quote! {}
+                } else {
+                    field_table(&ty_ident, &input.generics, &table_fields)
+                };
                 quote! {
                     impl #impl_generics #ty_ident #ty_generics #where_clause {
                         #(#acc_fns)*
                         #(#with_fns)*
                     }
+                    #table
+                    #pin
+                    #uninit
                 }
             }
             Fields::Unit => {
@@ -143,75 +763,636 @@ fn expand(input: DeriveInput) -> proc_macro2::TokenStream {
 /// Derive field accessors for a struct (named or tuple).
 ///
 /// Generates, for each field:
-/// - `pub const acc_*() -> pathmod::Accessor<Self, FieldTy>` accessors, and
+/// - `const acc_*() -> pathmod::Accessor<Self, FieldTy>` accessors, and
 /// - `with_*` reconstruction helpers that consume `self` and replace just that field.
 ///
+/// For `#[repr(packed)]` structs the accessors are `pathmod::UnalignedAccessor`s,
+/// which only move values in and out, and no `FieldTable` is emitted.
+///
+/// The generated items have the same visibility as their field, so private fields
+/// stay private. Override it with `#[pathmod(vis = "pub(crate)")]` on the struct (for
+/// every field) or on a single field, which takes precedence.
+///
+/// It also implements `pathmod::FieldTable`, the static field metadata used to resolve
+/// runtime string paths with `DynAccessor::resolve`. Since that impl is usable wherever
+/// the struct is, it only lists fields whose generated items are at least as visible as
+/// the struct itself: the private fields of a `pub` struct cannot be resolved.
+///
+/// With `#[pathmod(uninit)]` on the struct, each field also gets
+/// `const init_*() -> pathmod::InitField<Self, FieldTy>` and the struct implements
+/// `pathmod::InitFields`, so it can be built in place with `pathmod::UninitBuilder`.
+///
+/// Fields marked `#[pathmod(pin)]` are structurally pinned: each gets a
+/// `const pin_*() -> pathmod::PinAccessor<Self, FieldTy>`, and the struct gets
+/// `project_pin(self: Pin<&mut Self>) -> <Type>Projection<'_>`, a generated struct with
+/// `Pin<&mut FieldTy>` for pinned fields and `&mut FieldTy` for the others. The struct
+/// is then `Unpin` only if every pinned field is; implementing `Drop` or `Unpin` for
+/// it, or making it `#[repr(packed)]`, is a compile error.
+///
 /// See the crate-level docs for usage examples.
-#[proc_macro_derive(Accessor)]
+#[proc_macro_derive(Accessor, attributes(pathmod))]
 pub fn accessor_derive(input: TokenStream) -> TokenStream {
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let ts = expand(input);
     TokenStream::from(ts)
 }
 
+/// Emit the helpers for one enum variant.
+///
+/// Every variant kind is matched and built with braced syntax (`Self::V { 0: a }`),
+/// which works for unit, tuple and struct-like variants alike.
+fn variant_methods(
+    v: &syn::Variant,
+    name: &str,
+    default: Option<&Ident>,
+    names: &mut Vec<Ident>,
+) -> proc_macro2::TokenStream {
+    let v_ident = &v.ident;
+    let is_fn = format_ident!("is_{}", name);
+    let as_fn = format_ident!("as_{}", name);
+    let as_mut_fn = format_ident!("as_{}_mut", name);
+    let set_fn = format_ident!("set_{}", name);
+    let map_fn = format_ident!("map_{}", name);
+    let prism_fn = format_ident!("prism_{}", name);
+    let into_fn = format_ident!("into_{}", name);
+    let take_fn = format_ident!("take_{}", name);
+    let replace_fn = format_ident!("replace_{}", name);
+
+    let named = matches!(v.fields, Fields::Named(_));
+    let count = v.fields.len();
+    let members: Vec<syn::Member> = v
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(i.into()),
+        })
+        .collect();
+    // Bindings double as `set_*` parameter names.
+    let binds: Vec<Ident> = v
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => ident.clone(),
+            None if count == 1 => format_ident!("val"),
+            None => format_ident!("f{}", i),
+        })
+        .collect();
+    let tys: Vec<&syn::Type> = v.fields.iter().map(|f| &f.ty).collect();
+    // Named fields bind under their own name, so use shorthand to avoid lints.
+    let field_pat = |member: &syn::Member, bind: &Ident| match member {
+        syn::Member::Named(_) => quote! { #bind },
+        syn::Member::Unnamed(_) => quote! { #member: #bind },
+    };
+    let field_pats: Vec<_> = members
+        .iter()
+        .zip(&binds)
+        .map(|(m, b)| field_pat(m, b))
+        .collect();
+    let pat = quote! { Self::#v_ident { #(#field_pats),* } };
+    // What `into_*` and `take_*` move out: `()`, the single field, or a tuple.
+    let (payload, payload_ty) = match count {
+        1 => (quote! { #(#binds)* }, quote! { #(#tys)* }),
+        _ => (quote! { (#(#binds),*) }, quote! { (#(#tys),*) }),
+    };
+
+    names.extend([
+        is_fn.clone(),
+        set_fn.clone(),
+        into_fn.clone(),
+        replace_fn.clone(),
+    ]);
+    match count {
+        0 => names.push(prism_fn.clone()),
+        1 => names.extend([
+            as_fn.clone(),
+            as_mut_fn.clone(),
+            map_fn.clone(),
+            prism_fn.clone(),
+        ]),
+        _ => names.extend([as_fn.clone(), as_mut_fn.clone(), map_fn.clone()]),
+    }
+
+    let mut out = quote! {
+        #[inline]
+        pub fn #is_fn(&self) -> bool { matches!(self, Self::#v_ident { .. }) }
+        #[inline]
+        pub fn #set_fn(&mut self, #(#binds: #tys),*) { *self = #pat; }
+        /// Move the payload out if `self` is this variant, else give `self` back.
+        #[inline]
+        pub fn #into_fn(self) -> Result<#payload_ty, Self> { match self { #pat => Ok(#payload), other => Err(other) } }
+        /// Switch to this variant, returning the previous value.
+        #[inline]
+        pub fn #replace_fn(&mut self, #(#binds: #tys),*) -> Self { core::mem::replace(self, #pat) }
+    };
+
+    if let Some(default) = default.filter(|d| *d != v_ident) {
+        names.push(take_fn.clone());
+        out.extend(quote! {
+            /// Move the payload out if `self` is this variant, leaving the default
+            /// variant in its place.
+            #[inline]
+            pub fn #take_fn(&mut self) -> Option<#payload_ty> {
+                match core::mem::replace(self, Self::#default {}).#into_fn() {
+                    Ok(payload) => Some(payload),
+                    Err(other) => { *self = other; None }
+                }
+            }
+        });
+    }
+
+    match count {
+        0 => out.extend(quote! {
+            /// Prism focusing this variant, whose payload is `()`.
+            pub const fn #prism_fn() -> pathmod::Prism<Self, ()> {
+                pathmod::Prism::new(
+                    |s| if let Self::#v_ident { .. } = s { Some(&()) } else { None },
+                    |s| if let Self::#v_ident { .. } = s { Some(pathmod::__private::unit_mut()) } else { None },
+                    |()| Self::#v_ident {},
+                )
+            }
+        }),
+        1 => {
+            let ty = tys[0];
+            out.extend(quote! {
+                #[inline]
+                pub fn #as_fn(&self) -> Option<& #ty> { if let #pat = self { Some(#(#binds)*) } else { None } }
+                #[inline]
+                pub fn #as_mut_fn(&mut self) -> Option<&mut #ty> { if let #pat = self { Some(#(#binds)*) } else { None } }
+                #[inline]
+                pub fn #map_fn(&mut self, f: impl FnOnce(&mut #ty)) { if let #pat = self { f(#(#binds)*); } }
+                /// Prism focusing the payload of this variant.
+                pub const fn #prism_fn() -> pathmod::Prism<Self, #ty> {
+                    pathmod::Prism::new(
+                        |s| if let #pat = s { Some(#(#binds)*) } else { None },
+                        |s| if let #pat = s { Some(#(#binds)*) } else { None },
+                        |#(#binds)*| #pat,
+                    )
+                }
+            });
+        }
+        _ => out.extend(quote! {
+            #[inline]
+            pub fn #as_fn(&self) -> Option<(#(&#tys),*)> { if let #pat = self { Some((#(#binds),*)) } else { None } }
+            #[inline]
+            pub fn #as_mut_fn(&mut self) -> Option<(#(&mut #tys),*)> { if let #pat = self { Some((#(#binds),*)) } else { None } }
+            #[inline]
+            pub fn #map_fn(&mut self, f: impl FnOnce(#(&mut #tys),*)) { if let #pat = self { f(#(#binds),*); } }
+        }),
+    }
+
+    // Per-field helpers for struct-like variants and multi-field tuple variants.
+    if named || count > 1 {
+        for ((member, bind), ty) in members.iter().zip(&binds).zip(&tys) {
+            let suffix = match member {
+                syn::Member::Named(ident) => ident.unraw().to_string(),
+                syn::Member::Unnamed(index) => index.index.to_string(),
+            };
+            let field_fn = format_ident!("as_{}_{}", name, suffix);
+            let field_mut_fn = format_ident!("as_{}_{}_mut", name, suffix);
+            let one = field_pat(member, bind);
+            names.extend([field_fn.clone(), field_mut_fn.clone()]);
+            out.extend(quote! {
+                #[inline]
+                pub fn #field_fn(&self) -> Option<& #ty> { if let Self::#v_ident { #one, .. } = self { Some(#bind) } else { None } }
+                #[inline]
+                pub fn #field_mut_fn(&mut self) -> Option<&mut #ty> { if let Self::#v_ident { #one, .. } = self { Some(#bind) } else { None } }
+            });
+        }
+    }
+    out
+}
+
+/// Convert a `CamelCase` identifier to `snake_case`, keeping acronyms together
+/// (`HttpError` and `HTTPError` both become `http_error`).
+fn to_snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.trim_start_matches("r#").chars().collect();
+    let mut out = String::with_capacity(chars.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev != '_'
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_lower))
+            {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// Enum-level options of `#[derive(EnumAccess)]`.
+struct EnumOpts {
+    /// `#[pathmod(rename_all)]`: how variant names become method names
+    /// (`"snake_case"` by default, or `"lowercase"`).
+    convert: fn(&str) -> String,
+    /// `#[pathmod(kind)]` or `#[pathmod(kind = "Name")]`: name of the generated
+    /// fieldless kind enum (`<Enum>Kind` by default).
+    kind: Option<Ident>,
+}
+
+fn enum_options(ty_ident: &Ident, attrs: &[syn::Attribute]) -> syn::Result<EnumOpts> {
+    let mut opts = EnumOpts {
+        convert: to_snake_case,
+        kind: None,
+    };
+    for (key, lit) in pathmod_attrs(attrs, &["rename_all", "kind"], &["kind"])? {
+        match (key.to_string().as_str(), lit) {
+            ("rename_all", Some(lit)) => {
+                opts.convert = match lit.value().as_str() {
+                    "snake_case" => to_snake_case,
+                    "lowercase" => |s: &str| s.trim_start_matches("r#").to_lowercase(),
+                    _ => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "unsupported rename_all, expected \"snake_case\" or \"lowercase\"",
+                        ))
+                    }
+                };
+            }
+            (_, Some(lit)) => {
+                opts.kind =
+                    Some(lit.parse::<Ident>().map_err(|_| {
+                        syn::Error::new(lit.span(), "kind must be a valid type name")
+                    })?);
+            }
+            (_, None) => opts.kind = Some(format_ident!("{}Kind", ty_ident, span = key.span())),
+        }
+    }
+    Ok(opts)
+}
+
+/// Per-variant options of `#[derive(EnumAccess)]`.
+struct VariantOpts {
+    /// Base of the generated method names: `#[pathmod(rename)]` if given, else the
+    /// variant name converted by the enum's `#[pathmod(rename_all)]`.
+    name: String,
+    /// `#[pathmod(from)]`: implement `From<Payload>` for the enum and
+    /// `TryFrom<Enum>` for the payload.
+    from: bool,
+}
+
+fn variant_options(
+    convert: fn(&str) -> String,
+    variants: &[&syn::Variant],
+) -> syn::Result<Vec<VariantOpts>> {
+    variants
+        .iter()
+        .map(|v| {
+            let mut opts = VariantOpts {
+                name: convert(&v.ident.to_string()),
+                from: false,
+            };
+            for (key, lit) in pathmod_attrs(&v.attrs, &["rename"], &["from"])? {
+                match lit {
+                    Some(lit) => {
+                        syn::parse_str::<Ident>(&lit.value()).map_err(|_| {
+                            syn::Error::new(lit.span(), "rename must be a valid identifier")
+                        })?;
+                        opts.name = lit.value();
+                    }
+                    None if v.fields.len() != 1 => {
+                        return Err(syn::Error::new_spanned(
+                            key,
+                            "#[pathmod(from)] requires a variant with exactly one field",
+                        ))
+                    }
+                    None => opts.from = true,
+                }
+            }
+            Ok(opts)
+        })
+        .collect()
+}
+
+/// Total accessors for the named fields that every variant has with the same type:
+/// `<field>()`, `<field>_mut()` and `lens_<field>() -> pathmod::Lens<Self, FieldTy>`.
+/// The generated method names are appended to `names` with their field.
+fn shared_field_methods<'a>(
+    variants: &[&'a syn::Variant],
+    names: &mut Vec<(Ident, &'a Ident)>,
+) -> proc_macro2::TokenStream {
+    let Some((first, rest)) = variants.split_first() else {
+        return quote! {};
+    };
+    let has_field = |v: &syn::Variant, ident: &Ident, ty: &str| {
+        v.fields.iter().any(|f| {
+            let f_ty = &f.ty;
+            f.ident.as_ref() == Some(ident) && quote!(#f_ty).to_string() == ty
+        })
+    };
+    let mut out = proc_macro2::TokenStream::new();
+    for field in &first.fields {
+        let (Some(ident), ty) = (&field.ident, &field.ty) else {
+            continue;
+        };
+        let ty_str = quote!(#ty).to_string();
+        if !rest.iter().all(|v| has_field(v, ident, &ty_str)) {
+            continue;
+        }
+        let v_idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+        let mut_fn = format_ident!("{}_mut", ident);
+        let lens_fn = format_ident!("lens_{}", ident);
+        for method in [ident.clone(), mut_fn.clone(), lens_fn.clone()] {
+            names.push((method, ident));
+        }
+        let doc = format!("Borrow the `{ident}` field, which every variant has.");
+        let mut_doc = format!("Borrow the `{ident}` field mutably, whichever the variant.");
+        out.extend(quote! {
+            #[doc = #doc]
+            #[inline]
+            pub fn #ident(&self) -> &#ty { match self { #(Self::#v_idents { #ident, .. })|* => #ident } }
+            #[doc = #mut_doc]
+            #[inline]
+            pub fn #mut_fn(&mut self) -> &mut #ty { match self { #(Self::#v_idents { #ident, .. })|* => #ident } }
+            /// Lens focusing this field in every variant; composes with accessors.
+            pub const fn #lens_fn() -> pathmod::Lens<Self, #ty> {
+                pathmod::Lens::new(Self::#ident, Self::#mut_fn)
+            }
+        });
+    }
+    out
+}
+
+/// The fieldless mirror `kind` of the enum, with `ALL`, `as_str`, `Display` and
+/// `FromStr`, plus the `kind()` method on the enum itself.
+fn kind_enum(
+    vis: &syn::Visibility,
+    ty_ident: &Ident,
+    generics: &syn::Generics,
+    kind: &Ident,
+    variants: &[&syn::Variant],
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let strs: Vec<String> = idents
+        .iter()
+        .map(|i| i.to_string().trim_start_matches("r#").to_owned())
+        .collect();
+    let len = idents.len();
+    let doc = format!("The variants of [`{ty_ident}`] without their payloads.");
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #vis enum #kind { #(#idents),* }
+
+        impl #kind {
+            /// Every kind, in declaration order.
+            pub const ALL: [Self; #len] = [#(Self::#idents),*];
+
+            /// The variant name.
+            pub const fn as_str(self) -> &'static str {
+                match self { #(Self::#idents => #strs,)* }
+            }
+        }
+
+        impl core::fmt::Display for #kind {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl core::str::FromStr for #kind {
+            type Err = pathmod::ParseKindError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#strs => Ok(Self::#idents),)*
+                    _ => Err(pathmod::ParseKindError { input: s.into(), expected: &[#(#strs),*] }),
+                }
+            }
+        }
+
+        impl #impl_generics #ty_ident #ty_generics #where_clause {
+            /// Which variant `self` is.
+            #[inline]
+            pub const fn kind(&self) -> #kind {
+                match *self { #(Self::#idents { .. } => #kind::#idents,)* }
+            }
+        }
+    }
+}
+
+/// What an `EnumAccess` method was generated for, to report name collisions.
+#[derive(Clone, Copy)]
+enum MethodOwner<'a> {
+    Variant(&'a Ident),
+    SharedField(&'a Ident),
+    /// The `kind()` method of `#[pathmod(kind)]`, named by the kind enum.
+    Kind(&'a Ident),
+}
+
+impl MethodOwner<'_> {
+    fn ident(self) -> Ident {
+        match self {
+            MethodOwner::Variant(ident)
+            | MethodOwner::SharedField(ident)
+            | MethodOwner::Kind(ident) => ident.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for MethodOwner<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MethodOwner::Variant(ident) => write!(f, "variant `{ident}`"),
+            MethodOwner::SharedField(ident) => write!(f, "shared field `{ident}`"),
+            MethodOwner::Kind(ident) => write!(f, "kind enum `{ident}`"),
+        }
+    }
+}
+
+/// Reject methods generated twice, reporting each at the owner that came later:
+/// e.g. `Foo { bar }` and `FooBar(_)` both produce `as_foo_bar`, a field `is_b`
+/// shared by every variant collides with `is_b` for variant `B`, and a shared field
+/// `kind` with the `kind()` of `#[pathmod(kind)]`.
+fn check_collisions<'a>(
+    methods: impl IntoIterator<Item = (Ident, MethodOwner<'a>)>,
+) -> syn::Result<()> {
+    let mut owners: Vec<(Ident, MethodOwner)> = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+    let mut reported: Vec<Ident> = Vec::new();
+    for (method, owner) in methods {
+        let owner_ident = owner.ident();
+        let clash = owners.iter().find(|(m, _)| *m == method);
+        if let Some((_, other)) = clash.filter(|_| !reported.contains(&owner_ident)) {
+            let hint = match (owner, other) {
+                (MethodOwner::Variant(_), MethodOwner::Variant(_)) => {
+                    "use #[pathmod(rename = \"...\")] on one of them"
+                }
+                (MethodOwner::SharedField(_), MethodOwner::SharedField(_))
+                | (MethodOwner::Kind(_), MethodOwner::SharedField(_))
+                | (MethodOwner::SharedField(_), MethodOwner::Kind(_)) => "rename the field",
+                _ => "use #[pathmod(rename = \"...\")] on the variant",
+            };
+            let e = syn::Error::new_spanned(
+                &owner_ident,
+                format!("generated method `{method}` for {owner} collides with the one for {other}; {hint}"),
+            );
+            match &mut errors {
+                Some(all) => all.combine(e),
+                None => errors = Some(e),
+            }
+            reported.push(owner_ident);
+        }
+        owners.push((method, owner));
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// The type parameter that `ty` is, possibly behind the fundamental `&`, `&mut`,
+/// `Box` or `Pin`, which do not count as local types for the orphan rules.
+fn uncovered_type_param<'a>(ty: &syn::Type, generics: &'a syn::Generics) -> Option<&'a Ident> {
+    match ty {
+        syn::Type::Reference(r) => uncovered_type_param(&r.elem, generics),
+        syn::Type::Paren(p) => uncovered_type_param(&p.elem, generics),
+        syn::Type::Group(g) => uncovered_type_param(&g.elem, generics),
+        syn::Type::Path(p) if p.qself.is_none() => {
+            if let Some(param) = generics
+                .type_params()
+                .map(|tp| &tp.ident)
+                .find(|param| p.path.is_ident(*param))
+            {
+                return Some(param);
+            }
+            let last = p.path.segments.last()?;
+            if last.ident != "Box" && last.ident != "Pin" {
+                return None;
+            }
+            let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+                return None;
+            };
+            match args.args.first() {
+                Some(syn::GenericArgument::Type(inner)) if args.args.len() == 1 => {
+                    uncovered_type_param(inner, generics)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `From`/`TryFrom` impls between the enum and the payloads of `#[pathmod(from)]`
+/// variants. Two such variants with the same payload type would make `From`
+/// ambiguous, so the second one is rejected. A payload that is a bare type parameter
+/// `T` is rejected too: `From<T> for Enum<T>` overlaps core's `From<T> for T`. So is
+/// `&T`, `Box<T>` or `Pin<T>`, for which `TryFrom<Enum<T>>` breaks the orphan rules.
+fn conversion_impls(
+    ty_ident: &Ident,
+    generics: &syn::Generics,
+    variants: &[&syn::Variant],
+    opts: &[VariantOpts],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut seen: Vec<(String, &Ident)> = Vec::new();
+    let mut out = proc_macro2::TokenStream::new();
+    for (v, o) in variants.iter().zip(opts).filter(|(_, o)| o.from) {
+        let v_ident = &v.ident;
+        let field = v.fields.iter().next().expect("checked to have one field");
+        let ty = &field.ty;
+        if let Some(param) = uncovered_type_param(ty, generics) {
+            let msg = if matches!(ty, syn::Type::Path(p) if p.path.is_ident(param)) {
+                format!("#[pathmod(from)] cannot convert from the type parameter `{param}`: `From<{param}>` for the enum would overlap core's `impl<T> From<T> for T`")
+            } else {
+                format!("#[pathmod(from)] cannot convert from the type parameter `{param}` behind `&`, `Box` or `Pin`: it is not covered by a local type, so `TryFrom<Enum>` for the payload breaks the orphan rules")
+            };
+            return Err(syn::Error::new_spanned(ty, msg));
+        }
+        let key = quote!(#ty).to_string();
+        if let Some((_, other)) = seen.iter().find(|(k, _)| *k == key) {
+            return Err(syn::Error::new_spanned(
+                v_ident,
+                format!("variant `{other}` already converts from `{key}`; only one #[pathmod(from)] variant per payload type"),
+            ));
+        }
+        seen.push((key, v_ident));
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => quote!(0),
+        };
+        let into_fn = format_ident!("into_{}", o.name);
+        out.extend(quote! {
+            impl #impl_generics From<#ty> for #ty_ident #ty_generics #where_clause {
+                #[inline]
+                fn from(value: #ty) -> Self { Self::#v_ident { #member: value } }
+            }
+            impl #impl_generics TryFrom<#ty_ident #ty_generics> for #ty #where_clause {
+                type Error = #ty_ident #ty_generics;
+                #[inline]
+                fn try_from(value: #ty_ident #ty_generics) -> Result<Self, Self::Error> { value.#into_fn() }
+            }
+        });
+    }
+    Ok(out)
+}
+
 fn expand_enum(input: DeriveInput) -> proc_macro2::TokenStream {
-    // Note: Keep control flow linear to help coverage tools attribute regions cleanly.
     let ty_ident = input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     match input.data {
         Data::Enum(en) => {
-            // Build method sets per variant for single-field variants only
+            let variants: Vec<&syn::Variant> = en.variants.iter().collect();
+            let enum_opts = match enum_options(&ty_ident, &input.attrs) {
+                Ok(opts) => opts,
+                Err(e) => return e.to_compile_error(),
+            };
+            let opts = match variant_options(enum_opts.convert, &variants) {
+                Ok(opts) => opts,
+                Err(e) => return e.to_compile_error(),
+            };
+            // `take_*` leaves the `#[default]` variant behind; it is a unit variant, as
+            // required by `#[derive(Default)]`.
+            let default = variants
+                .iter()
+                .find(|v| v.attrs.iter().any(|a| a.path().is_ident("default")))
+                .map(|v| &v.ident);
+            // Generated names must be unique across variants and shared fields.
+            let mut methods = Vec::new();
             let mut per_variant_tokens = Vec::new();
-            let mut error_msg: Option<&'static str> = None;
-            for v in en.variants.iter() {
-                let v_ident = &v.ident;
-                match &v.fields {
-                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
-                        let fty = &fields.unnamed.first().unwrap().ty;
-                        let is_fn = format_ident!("is_{}", v_ident.to_string().to_lowercase());
-                        let as_fn = format_ident!("as_{}", v_ident.to_string().to_lowercase());
-                        let as_mut_fn =
-                            format_ident!("as_{}_mut", v_ident.to_string().to_lowercase());
-                        let set_fn = format_ident!("set_{}", v_ident.to_string().to_lowercase());
-                        let map_fn = format_ident!("map_{}", v_ident.to_string().to_lowercase());
-                        per_variant_tokens.push(quote! {
-                            #[inline]
-                            pub fn #is_fn(&self) -> bool { matches!(self, Self::#v_ident(_)) }
-                            #[inline]
-                            pub fn #as_fn(&self) -> Option<& #fty> { if let Self::#v_ident(ref v) = self { Some(v) } else { None } }
-                            #[inline]
-                            pub fn #as_mut_fn(&mut self) -> Option<&mut #fty> { if let Self::#v_ident(ref mut v) = self { Some(v) } else { None } }
-                            #[inline]
-                            pub fn #set_fn(&mut self, val: #fty) { *self = Self::#v_ident(val); }
-                            #[inline]
-                            pub fn #map_fn(&mut self, f: impl FnOnce(&mut #fty)) { if let Self::#v_ident(ref mut v) = self { f(v); } }
-                        });
-                    }
-                    Fields::Named(fields) if fields.named.len() == 1 => {
-                        let _ = &fields; // keep pattern usage without warnings
-                        error_msg = Some("#[derive(EnumAccess)] currently supports only tuple variants with exactly one field; named-field single variants are not yet supported");
-                        break;
-                    }
-                    Fields::Unit => {
-                        error_msg = Some(
-                            "#[derive(EnumAccess)] does not support unit variants in this MVP",
-                        );
-                        break;
-                    }
-                    _ => {
-                        error_msg = Some("#[derive(EnumAccess)] supports only tuple variants with exactly one field");
-                        break;
-                    }
-                }
+            for (v, o) in variants.iter().zip(&opts) {
+                let mut names = Vec::new();
+                per_variant_tokens.push(variant_methods(v, &o.name, default, &mut names));
+                methods.extend(
+                    names
+                        .into_iter()
+                        .map(|m| (m, MethodOwner::Variant(&v.ident))),
+                );
             }
-            if let Some(msg) = error_msg {
-                return quote! { compile_error!(#msg); };
+            let mut shared_names = Vec::new();
+            let shared = shared_field_methods(&variants, &mut shared_names);
+            methods.extend(
+                shared_names
+                    .into_iter()
+                    .map(|(m, field)| (m, MethodOwner::SharedField(field))),
+            );
+            if let Some(kind) = &enum_opts.kind {
+                methods.push((format_ident!("kind"), MethodOwner::Kind(kind)));
             }
+            if let Err(e) = check_collisions(methods) {
+                return e.to_compile_error();
+            }
+            let conversions = match conversion_impls(&ty_ident, &input.generics, &variants, &opts) {
+                Ok(tokens) => tokens,
+                Err(e) => return e.to_compile_error(),
+            };
+            let kind = enum_opts
+                .kind
+                .map(|kind| kind_enum(&input.vis, &ty_ident, &input.generics, &kind, &variants));
             quote! {
                 impl #impl_generics #ty_ident #ty_generics #where_clause {
                     #(#per_variant_tokens)*
+                    #shared
                 }
+                #conversions
+                #kind
             }
         }
         _ => {
@@ -220,11 +1401,46 @@ fn expand_enum(input: DeriveInput) -> proc_macro2::TokenStream {
     }
 }
 
-/// Derive helpers for enum variants (MVP: tuple variants with exactly one field).
+/// Derive helpers for enum variants.
+///
+/// Generates per-variant helpers:
+/// - every variant: `is_*`, `set_*` and `replace_*` (taking one argument per field,
+///   `replace_*` returns the previous value), and `into_*(self) -> Result<Payload, Self>`
+///   where the payload is `()`, the single field or a tuple of the fields;
+/// - every variant but the default one, if the enum derives `Default` with a
+///   `#[default]` variant: `take_*(&mut self) -> Option<Payload>`, which leaves the
+///   default variant behind;
+/// - single-field variants: `as_*`/`as_*_mut` returning the payload, `map_*`, and
+///   `prism_*() -> pathmod::Prism<Self, FieldTy>`; unit variants get a
+///   `Prism<Self, ()>`;
+/// - multi-field variants: `as_*`/`as_*_mut` returning a tuple of references and
+///   `map_*` taking one argument per field;
+/// - struct-like and multi-field tuple variants: per-field `as_<variant>_<field>`
+///   and `as_<variant>_<field>_mut` (tuple fields are named by index).
 ///
-/// Generates per-variant helpers: `is_*`, `as_*`, `as_*_mut`, `set_*`, `map_*`.
-/// See crate-level docs for examples and limitations.
-#[proc_macro_derive(EnumAccess)]
+/// `<variant>` is the variant name in snake_case (`HttpError` becomes `http_error`).
+/// Use `#[pathmod(rename = "name")]` on a variant to pick another name, or
+/// `#[pathmod(rename_all = "lowercase")]` on the enum for plain lowercasing. Variants
+/// whose generated names collide are reported at the variant.
+///
+/// Named fields that every variant has with the same type also get total accessors
+/// `<field>()` and `<field>_mut()`, and `lens_<field>() -> pathmod::Lens<Self, FieldTy>`,
+/// which composes with struct accessors. Their names are checked for collisions with
+/// the per-variant helpers too.
+///
+/// `#[pathmod(kind)]` on the enum also generates a fieldless `<Enum>Kind` enum (or the
+/// name given by `#[pathmod(kind = "Name")]`) with the same variants, `ALL`,
+/// `as_str()`, `Display` and `FromStr` (using the variant names), and a
+/// `kind()` method on the enum, which a shared `kind` field would collide with.
+///
+/// `#[pathmod(from)]` on a single-field variant also implements `From<Payload>` for
+/// the enum and `TryFrom<Enum>` for the payload (with the enum as the error). At most
+/// one variant per payload type may opt in, and the payload cannot be a bare type
+/// parameter, whose `From` impl would overlap core's `From<T> for T`, nor one behind
+/// `&`, `Box` or `Pin`, for which `TryFrom` would break the orphan rules.
+///
+/// See crate-level docs for examples.
+#[proc_macro_derive(EnumAccess, attributes(pathmod))]
 pub fn enum_access_derive(input: TokenStream) -> TokenStream {
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let ts = expand_enum(input);
@@ -245,6 +1461,128 @@ mod tests {
         let s = out.to_string();
         assert!(s.contains("acc_a"));
         assert!(s.contains("acc_b"));
+        assert!(s.contains("FieldTable") && s.contains("TableProbe"));
+    }
+
+    #[test]
+    fn generated_items_follow_field_visibility() {
+        let di: DeriveInput = parse_quote! {
+            #[pathmod(vis = "pub(crate)")]
+            pub struct S {
+                pub a: i32,
+                #[pathmod(vis = "pub(super)")]
+                b: i64,
+            }
+        };
+        let s = expand(di).to_string();
+        assert!(s.contains("pub (crate) const fn acc_a"));
+        assert!(s.contains("pub (super) const fn acc_b"));
+
+        let di: DeriveInput = parse_quote! { pub struct S { pub a: i32, b: i64 } };
+        let s = expand(di).to_string();
+        assert!(s.contains("pub const fn acc_a"));
+        assert!(s.contains("] const fn acc_b") && s.contains("] fn with_b"));
+    }
+
+    #[test]
+    fn packed_structs_get_unaligned_accessors() {
+        let di: DeriveInput = parse_quote! {
+            #[repr(C, packed(2))]
+            struct H { a: u8, b: u32 }
+        };
+        let s = expand(di).to_string();
+        assert!(s.contains("UnalignedAccessor"));
+        assert!(!s.contains("FieldTable"));
+    }
+
+    #[test]
+    fn unsized_tails_get_tail_accessors() {
+        let di: DeriveInput = parse_quote! { struct P { len: u16, data: [u8] } };
+        let s = expand(di).to_string();
+        assert!(s.contains("TailAccessor < P , [u8] >"));
+        assert!(s.contains("Accessor < P , u16 >"));
+        assert!(!s.contains("with_"));
+
+        let di: DeriveInput = parse_quote! { struct W<T: ?Sized> { tag: u8, value: T } };
+        let s = expand(di).to_string();
+        assert!(s.contains("TailAccessor < W < T > , T >"));
+        assert!(s.contains("with_tag") && !s.contains("with_value"));
+        assert!(s.contains("where Self : Sized"));
+    }
+
+    #[test]
+    fn pinned_fields_get_pin_projection() {
+        let di: DeriveInput = parse_quote! { struct S { a: i32 } };
+        assert!(!expand(di).to_string().contains("project_pin"));
+
+        let di: DeriveInput = parse_quote! {
+            pub struct Task<F> { #[pathmod(pin)] fut: F, pub polls: u32 }
+        };
+        let s = expand(di).to_string();
+        assert!(s.contains("const fn pin_fut () -> pathmod :: PinAccessor < Task < F > , F >"));
+        assert!(s.contains("Pin accessor to the structurally pinned `fut` field."));
+        assert!(!s.contains("pin_polls"));
+        assert!(s.contains("fut : core :: pin :: Pin < & '__pin mut F >"));
+        assert!(s.contains("pub polls : & '__pin mut u32"));
+        assert!(s.contains("Wrapper < '__pin , (F ,) > : core :: marker :: Unpin"));
+        assert!(s.contains("MustNotImplDrop for Task < F >"));
+
+        let di: DeriveInput = parse_quote! { struct P(#[pathmod(pin)] i32, i64); };
+        let s = expand(di).to_string();
+        assert!(s.contains("fn pin_0") && s.contains("struct PProjection < '__pin > ("));
+
+        let di: DeriveInput = parse_quote! { #[pathmod(pin)] struct S { a: i32 } };
+        assert!(expand(di)
+            .to_string()
+            .contains("unsupported pathmod attribute"));
+        let di: DeriveInput = parse_quote! {
+            #[repr(packed)] struct S { #[pathmod(pin)] a: i32 }
+        };
+        assert!(expand(di)
+            .to_string()
+            .contains("cannot be used on #[repr(packed)]"));
+    }
+
+    #[test]
+    fn uninit_attribute_emits_init_fields() {
+        let di: DeriveInput = parse_quote! { struct S { a: i32 } };
+        assert!(!expand(di).to_string().contains("InitFields"));
+
+        let di: DeriveInput = parse_quote! {
+            #[pathmod(uninit)] pub struct S<T> { pub a: T, b: String }
+        };
+        let s = expand(di).to_string();
+        assert!(s.contains("pub const fn init_a () -> pathmod :: InitField < S < T > , T >"));
+        assert!(s.contains("fn init_b () -> pathmod :: InitField < S < T > , String >"));
+        assert!(s.contains("unsafe impl < T > pathmod :: InitFields for S < T >"));
+        assert!(s.contains("FIELD_NAMES : & 'static [& 'static str] = & [\"a\" , \"b\"]"));
+        assert!(s.contains("Handle to the `b` field for `pathmod::UninitBuilder`."));
+        assert!(s.contains("1usize => core :: ptr :: drop_in_place (& raw mut (* this) . b)"));
+
+        let di: DeriveInput = parse_quote! { #[pathmod(uninit)] struct P(u8, u16); };
+        assert!(expand(di).to_string().contains("fn init_1"));
+
+        let di: DeriveInput = parse_quote! {
+            #[pathmod(uninit)] #[repr(packed)] struct S { a: i32 }
+        };
+        assert!(expand(di)
+            .to_string()
+            .contains("cannot be used on #[repr(packed)]"));
+        let di: DeriveInput = parse_quote! { #[pathmod(uninit)] struct S { len: u8, data: [u8] } };
+        assert!(expand(di).to_string().contains("unsized field"));
+        let di: DeriveInput = parse_quote! { struct S { #[pathmod(uninit)] a: i32 } };
+        assert!(expand(di)
+            .to_string()
+            .contains("unsupported pathmod attribute"));
+    }
+
+    #[test]
+    fn errors_on_invalid_vis_attribute() {
+        let di: DeriveInput = parse_quote! {
+            struct S { #[pathmod(vis = "public")] a: i32 }
+        };
+        let s = expand(di).to_string();
+        assert!(s.contains("compile_error") && s.contains("invalid visibility"));
     }
 
     #[test]
@@ -291,35 +1629,177 @@ mod tests {
         assert!(s.contains("as_text_mut"));
         assert!(s.contains("set_text"));
         assert!(s.contains("map_text"));
+        assert!(s.contains("prism_int"));
+        assert!(s.contains("prism_text"));
     }
 
     #[test]
-    fn enum_access_error_on_unit_variant() {
+    fn enum_access_unit_variant() {
         let di: DeriveInput = parse_quote! { enum E { A } };
         let out = expand_enum(di);
         let s = out.to_string();
-        assert!(s.contains("compile_error") && s.contains("does not support unit variants"));
+        assert!(s.contains("is_a") && s.contains("set_a") && s.contains("prism_a"));
+        assert!(!s.contains("as_a") && !s.contains("compile_error"));
     }
 
     #[test]
-    fn enum_access_error_on_multi_field_variant() {
-        let di: DeriveInput = parse_quote! { enum E { Both(i32, i32) } };
+    fn enum_access_multi_field_variant() {
+        let di: DeriveInput = parse_quote! { enum E { Both(i32, String) } };
         let out = expand_enum(di);
         let s = out.to_string();
-        assert!(
-            s.contains("compile_error")
-                && s.contains("supports only tuple variants with exactly one field")
-        );
+        assert!(s.contains("as_both") && s.contains("as_both_mut"));
+        assert!(s.contains("Option < (& i32 , & String) >"));
+        assert!(s.contains("as_both_0") && s.contains("as_both_1_mut"));
+        assert!(s.contains("set_both") && s.contains("map_both"));
+        // No prism without a single payload, and tuple fields are numbered from zero.
+        assert!(!s.contains("prism_both") && !s.contains("as_both_2"));
     }
 
     #[test]
-    fn enum_access_error_on_named_single_field_variant() {
-        let di: DeriveInput = parse_quote! { enum E { V { v: i32 } } };
+    fn enum_access_named_field_variants() {
+        let di: DeriveInput = parse_quote! { enum E { V { v: i32 }, P { x: i32, y: i64 } } };
         let out = expand_enum(di);
         let s = out.to_string();
-        assert!(
-            s.contains("compile_error") && s.contains("currently supports only tuple variants")
-        );
+        // A single named field still gets a prism; every named field gets helpers.
+        assert!(s.contains("prism_v") && s.contains("as_v_v"));
+        assert!(s.contains("as_p_x") && s.contains("as_p_y_mut"));
+        assert!(s.contains("Option < (& i32 , & i64) >"));
+        assert!(!s.contains("prism_p"));
+    }
+
+    #[test]
+    fn enum_access_snake_case_names() {
+        let di: DeriveInput = parse_quote! { enum E { HttpError(u16), HTTPStatus, Ipv4Addr(u32) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("is_http_error") && s.contains("prism_http_error"));
+        assert!(s.contains("is_http_status") && s.contains("as_ipv4_addr"));
+        assert_eq!(to_snake_case("Quit"), "quit");
+        assert_eq!(to_snake_case("ABC"), "abc");
+        assert_eq!(to_snake_case("r#Type"), "type");
+    }
+
+    #[test]
+    fn enum_access_rename_attributes() {
+        let di: DeriveInput = parse_quote! {
+            #[pathmod(rename_all = "lowercase")]
+            enum E { HttpError(u16), #[pathmod(rename = "ok")] Success(u16) }
+        };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("is_httperror") && s.contains("is_ok") && !s.contains("is_success"));
+
+        let di: DeriveInput = parse_quote! { #[pathmod(rename_all = "kebab-case")] enum E { A } };
+        assert!(expand_enum(di)
+            .to_string()
+            .contains("unsupported rename_all"));
+        let di: DeriveInput = parse_quote! { enum E { #[pathmod(rename = "not ok")] A } };
+        assert!(expand_enum(di)
+            .to_string()
+            .contains("rename must be a valid identifier"));
+    }
+
+    #[test]
+    fn enum_access_reports_name_collisions() {
+        let di: DeriveInput = parse_quote! { enum E { HttpError(u16), HTTPError(u16) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("compile_error") && s.contains("`is_http_error`"));
+        assert!(s.contains("variant `HTTPError` collides with the one for variant `HttpError`"));
+
+        let di: DeriveInput = parse_quote! { enum E { A { is_b: bool }, B { is_b: bool } } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("`is_b` for shared field `is_b` collides with the one for variant `B`"));
+
+        let di: DeriveInput = parse_quote! {
+            #[pathmod(kind)]
+            enum E { A { kind: u8 }, B { kind: u8 } }
+        };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains(
+            "`kind` for kind enum `EKind` collides with the one for shared field `kind`"
+        ));
+    }
+
+    #[test]
+    fn enum_access_ownership_helpers() {
+        let di: DeriveInput = parse_quote! { enum E { A(i32), B(u8, u8) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("into_a") && s.contains("Result < i32 , Self >"));
+        assert!(s.contains("replace_b") && s.contains("Result < (u8 , u8) , Self >"));
+        // No default variant, so nothing to leave behind.
+        assert!(!s.contains("take_a"));
+
+        let di: DeriveInput = parse_quote! { enum E { #[default] None, A(i32) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("take_a") && !s.contains("take_none"));
+    }
+
+    #[test]
+    fn enum_access_from_attribute() {
+        let di: DeriveInput = parse_quote! { enum E { #[pathmod(from)] A(i32), B(i32) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("From < i32 > for E") && s.contains("TryFrom < E > for i32"));
+
+        let di: DeriveInput = parse_quote! { enum E { #[pathmod(from)] A(i32, i32) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("requires a variant with exactly one field"));
+
+        let di: DeriveInput =
+            parse_quote! { enum E { #[pathmod(from)] A(i32), #[pathmod(from)] B(i32) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("variant `A` already converts from `i32`"));
+
+        let di: DeriveInput = parse_quote! { enum E<T> { #[pathmod(from)] A(T), B(Vec<T>) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("cannot convert from the type parameter `T`"));
+
+        let di: DeriveInput = parse_quote! { enum E<T> { #[pathmod(from)] A(Box<T>) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("type parameter `T` behind `&`, `Box` or `Pin`"));
+
+        let di: DeriveInput = parse_quote! { enum E<'a, T> { #[pathmod(from)] A(&'a mut T) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("the type parameter `T` behind"));
+
+        let di: DeriveInput = parse_quote! { enum E<T> { #[pathmod(from)] A(Vec<T>) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("From < Vec < T > > for E < T >"));
+    }
+
+    #[test]
+    fn enum_access_shared_fields() {
+        let di: DeriveInput = parse_quote! {
+            enum E { A { id: u64, a: i32 }, B { b: i32, id: u64 }, C { id: u32, a: i32 } }
+        };
+        // `id` differs in type in `C`, `a` is missing from `B`.
+        let s = expand_enum(di).to_string();
+        assert!(!s.contains("lens_id") && !s.contains("lens_a"));
+
+        let di: DeriveInput =
+            parse_quote! { enum E { A { id: u64, a: i32 }, B { b: i32, id: u64 } } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("pub fn id (& self) -> & u64") && s.contains("pub fn id_mut"));
+        assert!(s.contains("pathmod :: Lens < Self , u64 >") && !s.contains("lens_a"));
+
+        let di: DeriveInput = parse_quote! { enum E { A(u64), B(u64) } };
+        assert!(!expand_enum(di).to_string().contains("Lens"));
+    }
+
+    #[test]
+    fn enum_access_kind_enum() {
+        let di: DeriveInput = parse_quote! { enum Msg { Int(i32), Text(String) } };
+        assert!(!expand_enum(di).to_string().contains("MsgKind"));
+
+        let di: DeriveInput =
+            parse_quote! { #[pathmod(kind)] pub enum Msg { Int(i32), Text(String) } };
+        let s = expand_enum(di).to_string();
+        assert!(s.contains("pub enum MsgKind { Int , Text }"));
+        assert!(s.contains("fn kind (& self) -> MsgKind") && s.contains("FromStr for MsgKind"));
+
+        let di: DeriveInput = parse_quote! { #[pathmod(kind = "Tag")] enum Msg { Int(i32) } };
+        assert!(expand_enum(di).to_string().contains("enum Tag { Int }"));
+        let di: DeriveInput = parse_quote! { #[pathmod(kind = "no good")] enum Msg { Int(i32) } };
+        assert!(expand_enum(di)
+            .to_string()
+            .contains("kind must be a valid type name"));
     }
 
     #[test]
@@ -349,6 +1829,8 @@ mod tests {
         assert!(s.contains("acc_u"));
         // The output token stream should mention where-clause Debug (stringly check)
         assert!(s.contains("Debug") || s.contains("where"));
+        // Fields of generic type are leaves in the field table
+        assert!(s.contains("FieldTable") && !s.contains("TableProbe"));
     }
 
     // Exercise the zero-variant enum path (should generate an empty impl block)
@@ -7,12 +7,43 @@ fn derive_errors() {
     t.compile_fail("tests/ui/unit_struct.rs");
     // Visibility-related error when trying to use accessor on a private type
     t.compile_fail("tests/ui/visibility_private_type.rs");
+    // Generated items inherit field visibility unless overridden
+    t.compile_fail("tests/ui/private_field_accessor.rs");
+    t.pass("tests/ui/vis_override.rs");
+    t.compile_fail("tests/ui/vis_invalid.rs");
+    // Packed structs get by-value accessors that never form references
+    t.compile_fail("tests/ui/packed_no_reference.rs");
+    t.compile_fail("tests/ui/packed_unsized_tail.rs");
+    // #[pathmod(pin)] enforces the Unpin/Drop/packed rules of structural pinning
+    t.compile_fail("tests/ui/pin_drop.rs");
+    t.compile_fail("tests/ui/pin_unpin_impl.rs");
+    t.compile_fail("tests/ui/pin_packed.rs");
+    t.compile_fail("tests/ui/pin_not_unpin.rs");
+    // #[pathmod(uninit)] needs aligned, sized fields it can track in a bitset
+    t.compile_fail("tests/ui/uninit_packed.rs");
+    t.compile_fail("tests/ui/uninit_unsized.rs");
+    t.compile_fail("tests/ui/uninit_too_many_fields.rs");
     // Generics positive case: should compile
     t.pass("tests/ui/generic_ok.rs");
 
-    // EnumAccess derive negative cases
+    // EnumAccess derive: every variant kind is supported
     t.compile_fail("tests/ui/enum_non_enum.rs");
-    t.compile_fail("tests/ui/enum_unit.rs");
-    t.compile_fail("tests/ui/enum_multi.rs");
-    t.compile_fail("tests/ui/enum_named_single.rs");
+    t.pass("tests/ui/enum_unit.rs");
+    t.pass("tests/ui/enum_multi.rs");
+    t.pass("tests/ui/enum_named_single.rs");
+    t.pass("tests/ui/enum_raw_ident_field.rs");
+    // Method names are snake_case; colliding names are reported at the variant
+    t.compile_fail("tests/ui/enum_name_collision.rs");
+    t.compile_fail("tests/ui/enum_shared_field_collision.rs");
+    t.compile_fail("tests/ui/enum_kind_collision.rs");
+    // #[pathmod(from)] needs an unambiguous payload type
+    t.compile_fail("tests/ui/enum_from_ambiguous.rs");
+    t.compile_fail("tests/ui/enum_from_generic.rs");
+    t.compile_fail("tests/ui/enum_from_boxed_generic.rs");
+
+    // path! macro: misspelled segments are reported at the segment
+    t.compile_fail("tests/ui/path_misspelled.rs");
+    // path! never hands out a safe accessor to a union field
+    t.compile_fail("tests/ui/path_union.rs");
+    t.compile_fail("tests/ui/field_union.rs");
 }
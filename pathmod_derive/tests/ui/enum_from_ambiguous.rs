@@ -0,0 +1,12 @@
+use pathmod::prelude::*;
+
+// `From<u16>` could build either variant.
+#[derive(EnumAccess)]
+enum Code {
+    #[pathmod(from)]
+    Ok(u16),
+    #[pathmod(from)]
+    Err(u16),
+}
+
+fn main() {}
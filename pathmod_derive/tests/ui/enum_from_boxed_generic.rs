@@ -0,0 +1,12 @@
+use pathmod::prelude::*;
+
+// `TryFrom<Tree<T>> for Box<T>` implements a foreign trait for a type that is
+// not local: `Box` is fundamental, so `T` stays uncovered.
+#[derive(EnumAccess)]
+enum Tree<T> {
+    #[pathmod(from)]
+    Leaf(Box<T>),
+    Empty,
+}
+
+fn main() {}
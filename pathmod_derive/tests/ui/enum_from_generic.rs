@@ -0,0 +1,11 @@
+use pathmod::prelude::*;
+
+// `From<T> for Wrapper<T>` would overlap core's `impl<T> From<T> for T`.
+#[derive(EnumAccess)]
+enum Wrapper<T> {
+    #[pathmod(from)]
+    Value(T),
+    Empty,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use pathmod::prelude::*;
+
+// The shared `kind` field gets a `kind()` accessor, as does `#[pathmod(kind)]`.
+#[derive(EnumAccess)]
+#[pathmod(kind)]
+enum Token {
+    Word { kind: u8, text: String },
+    Number { kind: u8, value: i64 },
+}
+
+fn main() {}
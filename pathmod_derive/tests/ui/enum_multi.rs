@@ -1,6 +1,18 @@
 use pathmod::prelude::*;
 
-#[derive(EnumAccess)]
-enum M { Both(i32, i32) }
+#[derive(EnumAccess, Debug, PartialEq)]
+enum M {
+    Both(i32, String),
+}
 
-fn main() {}
+fn main() {
+    let mut m = M::Both(1, "a".to_string());
+    let _: Option<(&i32, &String)> = m.as_both();
+    let _: Option<&String> = m.as_both_1();
+    m.map_both(|n, s| s.push_str(&n.to_string()));
+    if let Some(n) = m.as_both_0_mut() {
+        *n += 1;
+    }
+    m.set_both(3, "c".to_string());
+    assert!(m.is_both());
+}
@@ -0,0 +1,10 @@
+use pathmod::prelude::*;
+
+// Both variants become `http_error` in snake_case.
+#[derive(EnumAccess)]
+enum Status {
+    HttpError(u16),
+    HTTPError(u16),
+}
+
+fn main() {}
@@ -1,6 +1,17 @@
 use pathmod::prelude::*;
 
-#[derive(EnumAccess)]
-enum V { Named { v: i32 } }
+#[derive(EnumAccess, Debug, PartialEq)]
+enum V {
+    Named { v: i32 },
+    Point { x: i32, y: i32 },
+}
 
-fn main() {}
+fn main() {
+    let mut p = V::Named { v: 1 };
+    let _: Option<&i32> = p.as_named();
+    let _: Option<&i32> = p.as_named_v();
+    let _: Prism<V, i32> = V::prism_named();
+    p.set_point(1, 2);
+    let _: Option<(&i32, &i32)> = p.as_point();
+    let _: Option<&mut i32> = p.as_point_y_mut();
+}
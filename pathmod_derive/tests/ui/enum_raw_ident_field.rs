@@ -0,0 +1,20 @@
+use pathmod::prelude::*;
+
+#[derive(EnumAccess, Debug, PartialEq)]
+enum Node {
+    Leaf { r#type: u8, x: u8 },
+    Pair { r#type: u8, r#ref: u16 },
+}
+
+fn main() {
+    let mut n = Node::Leaf { r#type: 1, x: 2 };
+    let _: Option<&u8> = n.as_leaf_type();
+    if let Some(t) = n.as_leaf_type_mut() {
+        *t += 1;
+    }
+    n.set_pair(3, 4);
+    let _: Option<&u16> = n.as_pair_ref();
+    // `type` is shared by every variant.
+    assert_eq!(*n.r#type(), 3);
+    let _: Lens<Node, u8> = Node::lens_type();
+}
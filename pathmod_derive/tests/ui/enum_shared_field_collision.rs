@@ -0,0 +1,11 @@
+use pathmod::prelude::*;
+
+// `is_open` is shared by every variant, so it gets an `is_open()` accessor, which is
+// also the variant check generated for `Open`.
+#[derive(EnumAccess)]
+enum Door {
+    Open { is_open: bool },
+    Closed { is_open: bool },
+}
+
+fn main() {}
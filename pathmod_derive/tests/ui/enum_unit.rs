@@ -1,6 +1,15 @@
 use pathmod::prelude::*;
 
-#[derive(EnumAccess)]
-enum E { A }
+#[derive(EnumAccess, Debug, PartialEq)]
+enum E {
+    A,
+    B(i32),
+}
 
-fn main() {}
+fn main() {
+    let mut e = E::B(1);
+    assert!(!e.is_a());
+    e.set_a();
+    assert!(e.is_a());
+    let _: Prism<E, ()> = E::prism_a();
+}
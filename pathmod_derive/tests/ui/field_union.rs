@@ -0,0 +1,10 @@
+union Bits {
+    byte: u8,
+    flag: bool,
+}
+
+fn main() {
+    let flag = pathmod::field!(Bits, flag);
+    let bits = Bits { byte: 2 };
+    let _ = flag.get(&bits);
+}
@@ -0,0 +1,15 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor)]
+#[repr(packed)]
+struct Header {
+    kind: u8,
+    length: u32,
+}
+
+fn main() {
+    let h = Header { kind: 1, length: 2 };
+    // Packed fields may be unaligned: only by-value reads are available.
+    let _ = Header::acc_length().read(&h);
+    let _: &u32 = Header::acc_length().get(&h);
+}
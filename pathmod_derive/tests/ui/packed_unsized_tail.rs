@@ -0,0 +1,11 @@
+use pathmod::prelude::*;
+
+// The slice could be unaligned, so no `&[u32]` may be handed out.
+#[derive(Accessor)]
+#[repr(C, packed)]
+struct Samples {
+    count: u8,
+    data: [u32],
+}
+
+fn main() {}
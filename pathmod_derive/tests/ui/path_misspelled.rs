@@ -0,0 +1,11 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor)]
+struct Address { city: String }
+
+#[derive(Accessor)]
+struct User { address: Address }
+
+fn main() {
+    let _ = pathmod::path!(User => address.ctiy);
+}
@@ -0,0 +1,14 @@
+use pathmod::prelude::*;
+
+// A `Drop` impl could move out of a pinned field, so it is rejected.
+#[derive(Accessor)]
+struct Task {
+    #[pathmod(pin)]
+    fut: std::future::Pending<()>,
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
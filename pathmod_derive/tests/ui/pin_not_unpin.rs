@@ -0,0 +1,17 @@
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+use pathmod::prelude::*;
+
+// A pinned `!Unpin` field makes the struct `!Unpin`, so it cannot be pinned safely.
+#[derive(Accessor)]
+struct Task {
+    #[pathmod(pin)]
+    state: PhantomPinned,
+    polls: u32,
+}
+
+fn main() {
+    let mut task = Task { state: PhantomPinned, polls: 0 };
+    let _ = Pin::new(&mut task).project_pin().polls;
+}
@@ -0,0 +1,10 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor)]
+#[repr(packed)]
+struct Task {
+    #[pathmod(pin)]
+    fut: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use pathmod::prelude::*;
+
+// `Unpin` is derived from the pinned fields; a manual impl would bypass it.
+#[derive(Accessor)]
+struct Task<F> {
+    #[pathmod(pin)]
+    fut: F,
+}
+
+impl<F> Unpin for Task<F> {}
+
+fn main() {}
@@ -0,0 +1,30 @@
+mod bank {
+    use pathmod::prelude::*;
+
+    #[derive(Accessor)]
+    pub struct Account {
+        pub owner: String,
+        balance: u64,
+    }
+
+    impl Account {
+        pub fn new(owner: &str) -> Self {
+            // Inside the module the private accessor is available.
+            let acc = Account {
+                owner: owner.to_string(),
+                balance: 0,
+            };
+            let _ = Account::acc_balance().get(&acc);
+            acc
+        }
+    }
+}
+
+fn main() {
+    let mut account = bank::Account::new("ada");
+    // Public fields keep public accessors.
+    bank::Account::acc_owner().set(&mut account, "grace".to_string());
+    // Private fields do not leak through the generated items.
+    let _ = bank::Account::acc_balance();
+    let _ = account.with_balance(1_000_000);
+}
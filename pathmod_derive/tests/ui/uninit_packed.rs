@@ -0,0 +1,11 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor)]
+#[pathmod(uninit)]
+#[repr(packed)]
+struct Header {
+    tag: u8,
+    len: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor)]
+#[pathmod(uninit)]
+struct Packet {
+    len: u16,
+    data: [u8],
+}
+
+fn main() {}
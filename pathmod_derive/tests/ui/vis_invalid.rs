@@ -0,0 +1,15 @@
+use pathmod::prelude::*;
+
+#[derive(Accessor)]
+struct Bad {
+    #[pathmod(vis = "public")]
+    a: i32,
+}
+
+#[derive(Accessor)]
+#[pathmod(visibility = "pub")]
+struct Unknown {
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+mod inner {
+    use pathmod::prelude::*;
+
+    #[derive(Accessor)]
+    #[pathmod(vis = "pub(crate)")]
+    pub struct Counter {
+        count: u32,
+        #[pathmod(vis = "pub")]
+        step: u32,
+    }
+
+    #[derive(Accessor)]
+    pub struct Pair(#[pathmod(vis = "pub")] u8, u8);
+
+    pub fn counter() -> Counter {
+        Counter { count: 0, step: 2 }
+    }
+}
+
+use pathmod::prelude::*;
+
+fn main() {
+    let mut c = inner::counter();
+    let step = *inner::Counter::acc_step().get(&c);
+    inner::Counter::acc_count().set_mut(&mut c, |n| *n += step);
+    let c = c.with_count(7);
+    let _ = inner::Counter::acc_count().get(&c);
+
+    let _: Accessor<inner::Pair, u8> = inner::Pair::acc_0();
+}